@@ -0,0 +1,101 @@
+use std::collections::HashMap;
+use std::fs;
+use std::path::Path;
+
+use toml::{Table, Value};
+
+use crate::BoxResult;
+
+/// The known answers for one input file.
+#[derive(Debug, Default)]
+pub struct Expected {
+    pub part1: Option<String>,
+    pub part2: Option<String>,
+}
+
+impl Expected {
+    pub fn part(&self, part: u32) -> Option<&str> {
+        match part {
+            1 => self.part1.as_deref(),
+            2 => self.part2.as_deref(),
+            _ => None,
+        }
+    }
+}
+
+/// Expected answers keyed by day and input file name, as checked in at `data/answers.toml`:
+///
+/// ```toml
+/// [day1."day1-sample.txt"]
+/// part1 = 24000
+/// part2 = 45000
+/// ```
+#[derive(Debug, Default)]
+pub struct Manifest {
+    entries: HashMap<(u32, String), Expected>,
+}
+
+fn answer(v: &Value) -> BoxResult<String> {
+    match v {
+        Value::String(s) => Ok(s.to_owned()),
+        Value::Integer(i) => Ok(i.to_string()),
+        _ => Err(format!("answer must be a string or integer, not {}", v.type_str()).into()),
+    }
+}
+
+impl Manifest {
+    pub const PATH: &'static str = "data/answers.toml";
+
+    pub fn parse(contents: &str) -> BoxResult<Self> {
+        let table: Table = contents.parse()?;
+        let mut entries = HashMap::new();
+        for (day_key, files) in table {
+            let day: u32 = day_key
+                .strip_prefix("day")
+                .and_then(|n| n.parse().ok())
+                .ok_or(format!("bad day {}", day_key))?;
+            let files = files
+                .as_table()
+                .ok_or(format!("{} must be a table", day_key))?;
+            for (file, parts) in files {
+                let parts = parts
+                    .as_table()
+                    .ok_or(format!("{}.{} must be a table", day_key, file))?;
+                let mut expected = Expected::default();
+                for (part, v) in parts {
+                    match part.as_str() {
+                        "part1" => expected.part1 = Some(answer(v)?),
+                        "part2" => expected.part2 = Some(answer(v)?),
+                        _ => {
+                            return Err(format!("unknown key {}.{}.{}", day_key, file, part).into())
+                        }
+                    }
+                }
+                entries.insert((day, file.to_owned()), expected);
+            }
+        }
+        Ok(Manifest { entries })
+    }
+
+    pub fn load<P: AsRef<Path>>(path: P) -> BoxResult<Self> {
+        let path = path.as_ref();
+        let contents =
+            fs::read_to_string(path).map_err(|e| format!("{}: {}", path.display(), e))?;
+        Manifest::parse(&contents)
+    }
+
+    pub fn get(&self, day: u32, file: &str) -> Option<&Expected> {
+        self.entries.get(&(day, file.to_owned()))
+    }
+
+    /// Every day and file name with answers, in order.
+    pub fn entries(&self) -> Vec<(u32, &str, &Expected)> {
+        let mut entries: Vec<_> = self
+            .entries
+            .iter()
+            .map(|((day, file), expected)| (*day, file.as_str(), expected))
+            .collect();
+        entries.sort_by_key(|&(day, file, _)| (day, file));
+        entries
+    }
+}
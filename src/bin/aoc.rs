@@ -0,0 +1,837 @@
+use std::collections::HashSet;
+use std::path::{Path, PathBuf};
+use std::time::{Duration, Instant};
+use std::{env, fs, io, process, thread};
+
+use advent2022::answers::Manifest;
+use advent2022::days::{day1, day2};
+use advent2022::image::FrameWriter;
+use advent2022::report::{self, Bench, Format, Outcome, Phase, Record, Run};
+use advent2022::vis::{Mode, Player};
+use advent2022::{gen, pool};
+use advent2022::{
+    open_input, print_answer, read_input, Answers, BoxResult, Day, Params, ParseError, Solution,
+    DAYS,
+};
+
+const USAGE: &str = "\
+Usage:
+  aoc run <day> [--part 1|2] [--input PATH | --input - | --sample] [--param NAME=VALUE]...
+                [--format text|json] [--time]
+  aoc run --all [--part 1|2] [--sample] [--format text|json] [--jobs N]
+  aoc bench <day> | --all [--runs N] [--part 1|2] [--input PATH | --sample] [--param NAME=VALUE]...
+                [--format text|json]
+  aoc crosscheck <day> | --all [--part 1|2] [--input PATH | --sample] [--param NAME=VALUE]...
+                [--gen N [--seed N] [--size N]]
+  aoc vis <day> [--part 1|2] [--input PATH | --sample] [--param NAME=VALUE]...
+                [--step] [--fps N] [--every N]
+  aoc image <day> [--input PATH | --sample] [--param NAME=VALUE]... [--out DIR] [--ppm]
+                [--scale N] [--frames [--part 1|2] [--every N]]
+  aoc watch <day> [--part 1|2] [--input PATH | --sample] [--param NAME=VALUE]... [--interval MS]
+  aoc calories [--top N] [--input PATH | --input - | --sample]
+                [--stats [--buckets N] [--format text|json]]
+  aoc guide [--input PATH | --input - | --sample]
+  aoc gen <day> [--seed N] [--size N]
+  aoc verify [<day>] [--manifest PATH]
+  aoc list";
+
+struct RunOpts {
+    days: Vec<Day>,
+    all: bool,
+    part: Option<u32>,
+    input: Option<PathBuf>,
+    sample: bool,
+    params: Params,
+    format: Format,
+    time: bool,
+    runs: usize,
+    /// How many generated inputs to use instead of the input file, and how to make them.
+    generate: u64,
+    seed: u64,
+    size: usize,
+    /// How `vis` plays frames: waiting after each, or this many a second, showing every `every`th.
+    step: bool,
+    fps: f64,
+    every: usize,
+    /// Where `image` saves pictures, in which format and at what size.
+    out: PathBuf,
+    ppm: bool,
+    scale: usize,
+    frames: bool,
+    /// How often `watch` checks for changes.
+    interval: Duration,
+    /// How many days `run --all` solves at once.
+    jobs: usize,
+}
+
+impl RunOpts {
+    fn parse(args: &[String]) -> BoxResult<Self> {
+        let mut opts = RunOpts {
+            days: Vec::new(),
+            all: false,
+            part: None,
+            input: None,
+            sample: false,
+            params: Params::new(),
+            format: Format::Text,
+            time: false,
+            runs: 10,
+            generate: 0,
+            seed: 0,
+            size: 20,
+            step: false,
+            fps: 10.0,
+            every: 1,
+            out: PathBuf::from("images"),
+            ppm: false,
+            scale: 4,
+            frames: false,
+            interval: Duration::from_millis(500),
+            jobs: pool::default_threads(),
+        };
+        let mut it = args.iter();
+        while let Some(arg) = it.next() {
+            match arg.as_str() {
+                "--all" => opts.all = true,
+                "--sample" => opts.sample = true,
+                "--time" => opts.time = true,
+                "--step" => opts.step = true,
+                "--ppm" => opts.ppm = true,
+                "--frames" => opts.frames = true,
+                "--out" => {
+                    let path = it.next().ok_or("--out needs a directory")?;
+                    opts.out = PathBuf::from(path);
+                }
+                "--interval" => {
+                    let ms = it.next().ok_or("--interval needs milliseconds")?;
+                    let ms = ms.parse().map_err(|_| format!("bad interval {}", ms))?;
+                    opts.interval = Duration::from_millis(ms);
+                }
+                "--scale" => {
+                    let scale = it.next().ok_or("--scale needs a value")?;
+                    opts.scale = match scale.parse() {
+                        Ok(n) if n > 0 => n,
+                        _ => return Err(format!("bad scale {}", scale).into()),
+                    }
+                }
+                "--fps" => {
+                    let fps = it.next().ok_or("--fps needs a value")?;
+                    opts.fps = match fps.parse() {
+                        Ok(n) if n > 0.0 => n,
+                        _ => return Err(format!("bad frame rate {}", fps).into()),
+                    }
+                }
+                "--every" => {
+                    let every = it.next().ok_or("--every needs a value")?;
+                    opts.every = match every.parse() {
+                        Ok(n) if n > 0 => n,
+                        _ => return Err(format!("bad step count {}", every).into()),
+                    }
+                }
+                "--jobs" => {
+                    let jobs = it.next().ok_or("--jobs needs a value")?;
+                    opts.jobs = jobs
+                        .parse()
+                        .map_err(|_| format!("bad job count {}", jobs))?;
+                    if opts.jobs == 0 {
+                        return Err("--jobs must be at least 1".into());
+                    }
+                }
+                "--runs" => {
+                    let runs = it.next().ok_or("--runs needs a value")?;
+                    opts.runs = match runs.parse() {
+                        Ok(n) if n > 0 => n,
+                        _ => return Err(format!("bad run count {}", runs).into()),
+                    }
+                }
+                "--gen" => {
+                    let n = it.next().ok_or("--gen needs a count")?;
+                    opts.generate = n.parse().map_err(|_| format!("bad input count {}", n))?;
+                }
+                "--seed" => {
+                    let s = it.next().ok_or("--seed needs a value")?;
+                    opts.seed = s.parse().map_err(|_| format!("bad seed {}", s))?;
+                }
+                "--size" => {
+                    let s = it.next().ok_or("--size needs a value")?;
+                    opts.size = s.parse().map_err(|_| format!("bad size {}", s))?;
+                }
+                "--part" => {
+                    let part = it.next().ok_or("--part needs a value")?;
+                    opts.part = match part.as_str() {
+                        "1" => Some(1),
+                        "2" => Some(2),
+                        _ => return Err(format!("no such part {}", part).into()),
+                    }
+                }
+                "--param" => {
+                    let param = it.next().ok_or("--param needs a name=value")?;
+                    opts.params.set_flag(param)?;
+                }
+                "--format" => {
+                    let format = it.next().ok_or("--format needs a value")?;
+                    opts.format = format.parse()?;
+                }
+                "--input" => {
+                    let path = it.next().ok_or("--input needs a path")?;
+                    opts.input = Some(PathBuf::from(path));
+                }
+                s if !s.starts_with('-') && opts.days.is_empty() => {
+                    let number: u32 = s.parse().map_err(|_| format!("bad day {}", s))?;
+                    let day = advent2022::day(number).ok_or(format!("no such day {}", s))?;
+                    opts.days.push(day);
+                }
+                _ => return Err(format!("unexpected argument {}", arg).into()),
+            }
+        }
+
+        if opts.all {
+            if !opts.days.is_empty() {
+                return Err("can't give both a day and --all".into());
+            }
+            if opts.input.is_some() {
+                return Err("can't use --input with --all".into());
+            }
+            if opts.params.names().next().is_some() {
+                return Err("can't use --param with --all".into());
+            }
+            opts.days = DAYS.to_vec();
+        }
+        if opts.days.is_empty() {
+            return Err("need a day or --all".into());
+        }
+        if opts.input.is_some() && opts.sample {
+            return Err("can't use both --input and --sample".into());
+        }
+        if opts.generate > 0 && (opts.input.is_some() || opts.sample) {
+            return Err("can't use --gen with --input or --sample".into());
+        }
+        Ok(opts)
+    }
+
+    fn path(&self, day: &Day) -> PathBuf {
+        match &self.input {
+            Some(p) => p.clone(),
+            None => day.input_path(self.sample),
+        }
+    }
+
+    /// The parameters for the input at `path`, overridden by any flags.
+    fn params(&self, path: &Path) -> BoxResult<Params> {
+        let mut params = Params::for_input(path)?;
+        params.extend(&self.params);
+        Ok(params)
+    }
+
+    /// Read and parse the day's input, with its parameters.
+    fn load(&self, day: &Day) -> BoxResult<Box<dyn Answers>> {
+        let path = self.path(day);
+        let contents = read_input(&path).map_err(|e| format!("{}: {}", path.display(), e))?;
+        day.parse_from(&path, &contents, &self.params(&path)?)
+    }
+
+    fn parts(&self) -> Vec<u32> {
+        [1, 2]
+            .into_iter()
+            .filter(|&p| self.part.is_none_or(|want| want == p))
+            .collect()
+    }
+
+    /// Parse and solve a day, timing each phase.
+    fn solve(&self, day: &Day) -> BoxResult<Run> {
+        let path = self.path(day);
+        let contents = read_input(&path).map_err(|e| format!("{}: {}", path.display(), e))?;
+        let params = self.params(&path)?;
+        let start = Instant::now();
+        let parsed = day.parse_from(&path, &contents, &params)?;
+        let mut run = Run {
+            answers: Vec::new(),
+            times: vec![(Phase::Parse, start.elapsed())],
+        };
+        for part in self.parts() {
+            let start = Instant::now();
+            let answer = parsed.part(part);
+            run.times.push((Phase::part(part), start.elapsed()));
+            run.answers.push((part, answer));
+        }
+        Ok(run)
+    }
+
+    fn run_day(&self, day: &Day) -> BoxResult<()> {
+        let run = self.solve(day)?;
+        for ((part, answer), (_, elapsed)) in run.answers.into_iter().zip(&run.times[1..]) {
+            match self.format {
+                Format::Text => print_answer(part, &answer),
+                Format::Json => {
+                    let record = Record {
+                        day: day.number,
+                        part,
+                        answer,
+                        elapsed: *elapsed,
+                    };
+                    println!("{}", record.to_json());
+                }
+            }
+        }
+        if self.time && self.format == Format::Text {
+            let times = run
+                .times
+                .iter()
+                .map(|(phase, t)| format!("{} {}", phase, report::duration(*t)))
+                .collect::<Vec<_>>();
+            println!("Time: {}", times.join(", "));
+        }
+        Ok(())
+    }
+
+    /// Solve a day `runs` times, timing each phase.
+    fn bench_day(&self, day: &Day) -> BoxResult<()> {
+        let path = self.path(day);
+        let contents = read_input(&path).map_err(|e| format!("{}: {}", path.display(), e))?;
+        let params = self.params(&path)?;
+        let mut phases = vec![Phase::Parse];
+        phases.extend(self.parts().into_iter().map(Phase::part));
+        let mut times = vec![Vec::with_capacity(self.runs); phases.len()];
+        for _ in 0..self.runs {
+            let start = Instant::now();
+            let parsed = day.parse_from(&path, &contents, &params)?;
+            times[0].push(start.elapsed());
+            for (i, part) in self.parts().into_iter().enumerate() {
+                let start = Instant::now();
+                parsed.part(part);
+                times[i + 1].push(start.elapsed());
+            }
+        }
+        for (phase, times) in phases.into_iter().zip(times) {
+            let bench = Bench::new(day.number, phase, times);
+            match self.format {
+                Format::Text => println!("{}", bench.to_row()),
+                Format::Json => println!("{}", bench.to_json()),
+            }
+        }
+        Ok(())
+    }
+
+    /// Run every solver for a day on the same inputs, returning how many answers disagreed.
+    fn crosscheck_day(&self, day: &Day) -> BoxResult<usize> {
+        let mut inputs = Vec::new();
+        if self.generate > 0 {
+            for seed in self.seed..self.seed + self.generate {
+                let input = gen::generate(day.number, seed, self.size).unwrap();
+                let parsed = day.parse_with(&input, &self.params)?;
+                inputs.push((format!("seed {} size {}", seed, self.size), parsed));
+            }
+        } else {
+            inputs.push((self.path(day).display().to_string(), self.load(day)?));
+        }
+
+        let mut disagreed = 0;
+        for (name, parsed) in inputs {
+            for variant in day.variants() {
+                if !parsed.handles(variant) {
+                    println!("{}: {} skipped, the input is too big for it", name, variant);
+                }
+            }
+            for part in self.parts() {
+                let mut answers = Vec::new();
+                for variant in day.variants() {
+                    let start = Instant::now();
+                    if let Some(answer) = parsed.variant(variant, part) {
+                        answers.push((variant, answer, start.elapsed()));
+                    }
+                }
+                if answers.is_empty() {
+                    continue;
+                }
+                let start = Instant::now();
+                answers.insert(0, ("main", parsed.part(part), start.elapsed()));
+
+                println!("{} part {}", name, part);
+                let expected = answers[0].1.clone();
+                for (variant, answer, elapsed) in answers {
+                    let mut line = format!(
+                        "  {:<14} {:<20} {:>10}",
+                        variant,
+                        answer,
+                        report::duration(elapsed)
+                    );
+                    if answer != expected {
+                        line += "  DISAGREES";
+                        disagreed += 1;
+                    }
+                    println!("{}", line);
+                }
+            }
+        }
+        Ok(disagreed)
+    }
+
+    /// Call `f` on each chosen day; with `--all`, failures are reported and skipped.
+    fn each_day(&self, f: impl Fn(&Self, &Day) -> BoxResult<()>) -> BoxResult<()> {
+        if !self.all {
+            return f(self, &self.days[0]);
+        }
+
+        let mut failed = 0;
+        for day in &self.days {
+            if let Err(e) = f(self, day) {
+                eprintln!("error: {}", e);
+                failed += 1;
+            }
+        }
+        if failed > 0 {
+            return Err(format!("{} days failed", failed).into());
+        }
+        Ok(())
+    }
+
+    fn run(&self) -> BoxResult<()> {
+        if self.all {
+            return self.run_all();
+        }
+        self.run_day(&self.days[0])
+    }
+
+    /// Solve every day at once, then print a table of how each went. A day that fails or
+    /// panics is reported along with the rest rather than stopping them.
+    fn run_all(&self) -> BoxResult<()> {
+        let start = Instant::now();
+        let results = pool::map(self.days.clone(), self.jobs, |day| {
+            self.solve(&day).map_err(|e| e.to_string())
+        });
+        let wall = start.elapsed();
+        let outcomes = self
+            .days
+            .iter()
+            .zip(results)
+            .map(|(day, result)| Outcome {
+                day: day.number,
+                title: day.title,
+                result: result.unwrap_or_else(|panic| Err(format!("panicked: {}", panic))),
+            })
+            .collect::<Vec<_>>();
+
+        match self.format {
+            Format::Text => {
+                for line in report::summary(&outcomes, wall) {
+                    println!("{}", line);
+                }
+            }
+            Format::Json => {
+                for outcome in &outcomes {
+                    let run = match &outcome.result {
+                        Ok(run) => run,
+                        Err(e) => {
+                            eprintln!("error: day {}: {}", outcome.day, e);
+                            continue;
+                        }
+                    };
+                    for (part, answer) in &run.answers {
+                        let record = Record {
+                            day: outcome.day,
+                            part: *part,
+                            answer: answer.clone(),
+                            elapsed: run.time(Phase::part(*part)).unwrap_or_default(),
+                        };
+                        println!("{}", record.to_json());
+                    }
+                }
+            }
+        }
+        match outcomes.iter().filter(|o| o.result.is_err()).count() {
+            0 => Ok(()),
+            n => Err(format!("{} days failed", n).into()),
+        }
+    }
+
+    fn crosscheck(&self) -> BoxResult<()> {
+        if self.format != Format::Text {
+            return Err("crosscheck only prints text".into());
+        }
+        if !self.all && self.days[0].variants().is_empty() {
+            return Err(format!("day {} has only one solver", self.days[0].number).into());
+        }
+        let disagreed = std::cell::Cell::new(0);
+        self.each_day(|opts, day| {
+            if day.variants().is_empty() {
+                return Ok(());
+            }
+            if opts.all {
+                println!("== Day {}: {} ==", day.number, day.title);
+            }
+            disagreed.set(disagreed.get() + opts.crosscheck_day(day)?);
+            Ok(())
+        })?;
+        match disagreed.get() {
+            0 => Ok(()),
+            n => Err(format!("{} answers disagreed", n).into()),
+        }
+    }
+
+    /// Play the simulation behind each part in the terminal, then print the answer.
+    fn visualize(&self) -> BoxResult<()> {
+        if self.all {
+            return Err("can't use vis with --all".into());
+        }
+        let day = &self.days[0];
+        let parsed = self.load(day)?;
+        let mode = if self.step { Mode::Step } else { Mode::Play };
+        for part in self.parts() {
+            let mut player = Player::new(io::stdout(), mode, self.fps, self.every);
+            let answer = parsed
+                .visualize(part, &mut player)
+                .ok_or(format!("day {} has nothing to show", day.number))?;
+            print_answer(part, answer);
+        }
+        Ok(())
+    }
+
+    /// Save pictures of the day's state, and with `--frames`, of each step of its simulations.
+    fn images(&self) -> BoxResult<()> {
+        if self.all {
+            return Err("can't use image with --all".into());
+        }
+        let day = &self.days[0];
+        let parsed = self.load(day)?;
+        let extension = if self.ppm { "ppm" } else { "png" };
+        fs::create_dir_all(&self.out)?;
+
+        let pictures = parsed.pictures();
+        if pictures.is_empty() && !self.frames {
+            return Err(format!("day {} has no pictures", day.number).into());
+        }
+        for (name, image) in pictures {
+            let path = self
+                .out
+                .join(format!("day{}-{}.{}", day.number, name, extension));
+            image.scaled(self.scale).save(&path)?;
+            println!("{}", path.display());
+        }
+
+        if self.frames {
+            for part in self.parts() {
+                let prefix = self.out.join(format!("day{}-part{}", day.number, part));
+                let mut writer = FrameWriter::new(prefix, extension, self.scale, self.every);
+                parsed
+                    .visualize(part, &mut writer)
+                    .ok_or(format!("day {} has nothing to show", day.number))?;
+                if let Some(e) = writer.error {
+                    return Err(e.into());
+                }
+                println!(
+                    "{} frames for part {} in {}",
+                    writer.written.len(),
+                    part,
+                    self.out.display()
+                );
+            }
+        }
+        Ok(())
+    }
+
+    /// Re-run a day whenever its input or parameter file changes, showing what changed.
+    fn watch(&self) -> BoxResult<()> {
+        if self.all {
+            return Err("can't use watch with --all".into());
+        }
+        let day = &self.days[0];
+        let path = self.path(day);
+        if path == Path::new("-") {
+            return Err("can't watch standard input".into());
+        }
+        let params_path = Params::path_for(&path);
+        println!("Watching {} and {}", path.display(), params_path.display());
+
+        // Comparing contents rather than modification times catches quick successive edits.
+        let mut seen = None;
+        let mut prev: Option<Run> = None;
+        loop {
+            let files = (fs::read(&path).ok(), fs::read(&params_path).ok());
+            if seen.as_ref() != Some(&files) {
+                seen = Some(files);
+                println!("== Day {}: {} ==", day.number, day.title);
+                match self.solve(day) {
+                    Ok(run) => {
+                        for line in run.diff(prev.as_ref()) {
+                            println!("{}", line);
+                        }
+                        prev = Some(run);
+                    }
+                    Err(e) => println!("error: {}", e),
+                }
+            }
+            thread::sleep(self.interval);
+        }
+    }
+
+    fn bench(&self) -> BoxResult<()> {
+        if self.format == Format::Text {
+            println!("{}", Bench::HEADER);
+        }
+        self.each_day(Self::bench_day)
+    }
+}
+
+fn verify(args: &[String]) -> BoxResult<()> {
+    let mut days = DAYS.to_vec();
+    let mut manifest_path = PathBuf::from(Manifest::PATH);
+    let mut it = args.iter();
+    while let Some(arg) = it.next() {
+        match arg.as_str() {
+            "--manifest" => {
+                let path = it.next().ok_or("--manifest needs a path")?;
+                manifest_path = PathBuf::from(path);
+            }
+            s if !s.starts_with('-') && days.len() == DAYS.len() => {
+                let number: u32 = s.parse().map_err(|_| format!("bad day {}", s))?;
+                let day = advent2022::day(number).ok_or(format!("no such day {}", s))?;
+                days = vec![day];
+            }
+            _ => return Err(format!("unexpected argument {}", arg).into()),
+        }
+    }
+    let manifest = Manifest::load(&manifest_path)?;
+
+    let (mut passed, mut failed, mut missing) = (0, 0, 0);
+    let mut found = HashSet::new();
+    for day in &days {
+        for path in day.input_files() {
+            let file = path.file_name().unwrap().to_string_lossy();
+            found.insert((day.number, file.to_string()));
+            let expected = manifest.get(day.number, &file);
+            let parsed = day.parse_file(&path);
+            for part in [1, 2] {
+                // Parts without a known answer aren't run, so slow solutions stay cheap to skip.
+                let status = match (expected.and_then(|e| e.part(part)), &parsed) {
+                    (None, _) => {
+                        missing += 1;
+                        "missing".to_owned()
+                    }
+                    (Some(_), Err(e)) => {
+                        failed += 1;
+                        format!("FAIL: {}", e)
+                    }
+                    (Some(want), Ok(p)) => {
+                        let got = p.part(part);
+                        if got == want {
+                            passed += 1;
+                            "pass".to_owned()
+                        } else {
+                            failed += 1;
+                            format!("FAIL: got {:?}, expected {:?}", got, want)
+                        }
+                    }
+                };
+                println!(
+                    "day{:<2}  {:<18} part{}  {}",
+                    day.number, file, part, status
+                );
+            }
+        }
+    }
+
+    // Answers for inputs that were renamed or removed would otherwise never be checked. Days
+    // that don't exist only come up when checking them all.
+    let checked = |day: u32| days.iter().any(|d| d.number == day) || days.len() == DAYS.len();
+    for (day, file, expected) in manifest.entries() {
+        if !checked(day) || found.contains(&(day, file.to_owned())) {
+            continue;
+        }
+        for part in [1, 2].into_iter().filter(|&p| expected.part(p).is_some()) {
+            failed += 1;
+            println!(
+                "day{:<2}  {:<18} part{}  FAIL: no such input file",
+                day, file, part
+            );
+        }
+    }
+
+    println!("{} passed, {} failed, {} missing", passed, failed, missing);
+    if failed > 0 {
+        return Err(format!("{} answers did not match", failed).into());
+    }
+    Ok(())
+}
+
+/// The best-stocked elves from a day 1 input of any size, which is read a line at a time, or
+/// with `--stats`, a report on all of them.
+fn calories(args: &[String]) -> BoxResult<()> {
+    let (mut k, mut stats, mut buckets, mut format) = (3, false, 10, Format::Text);
+    let mut path = advent2022::day(1).unwrap().input_path(false);
+    let mut it = args.iter();
+    while let Some(arg) = it.next() {
+        match arg.as_str() {
+            "--top" => {
+                let n = it.next().ok_or("--top needs a count")?;
+                k = n.parse().map_err(|_| format!("bad count {}", n))?;
+            }
+            "--stats" => stats = true,
+            "--buckets" => {
+                let n = it.next().ok_or("--buckets needs a count")?;
+                buckets = n.parse().map_err(|_| format!("bad count {}", n))?;
+            }
+            "--format" => format = it.next().ok_or("--format needs a value")?.parse()?,
+            "--input" => path = PathBuf::from(it.next().ok_or("--input needs a path")?),
+            "--sample" => path = advent2022::day(1).unwrap().input_path(true),
+            _ => return Err(format!("unexpected argument {}", arg).into()),
+        }
+    }
+
+    if stats {
+        // Medians and percentiles need every total, so this reads the whole input.
+        let contents = read_input(&path).map_err(|e| format!("{}: {}", path.display(), e))?;
+        let elves = day1::Elves::parse(&contents).map_err(|e| e.in_file(&path))?;
+        let stats = elves.stats(k, buckets);
+        match format {
+            Format::Text => print!("{}", stats.to_table()),
+            Format::Json => println!("{}", stats.to_json()),
+        }
+        return Ok(());
+    }
+    if format != Format::Text {
+        return Err("only --stats prints JSON".into());
+    }
+
+    let reader = open_input(&path).map_err(|e| format!("{}: {}", path.display(), e))?;
+    let top = day1::top_k(reader, k).map_err(|e| match e.downcast::<ParseError>() {
+        Ok(e) => e.in_file(&path).into(),
+        Err(e) => e,
+    })?;
+    for (rank, (elf, sum)) in top.largest().into_iter().enumerate() {
+        println!("{:>3}. elf {:<6} {}", rank + 1, elf + 1, sum);
+    }
+    println!("Total: {}", top.total());
+    Ok(())
+}
+
+/// How a day 2 strategy guide scores under every reading of its second column.
+fn guide(args: &[String]) -> BoxResult<()> {
+    let mut path = advent2022::day(2).unwrap().input_path(false);
+    let mut it = args.iter();
+    while let Some(arg) = it.next() {
+        match arg.as_str() {
+            "--input" => path = PathBuf::from(it.next().ok_or("--input needs a path")?),
+            "--sample" => path = advent2022::day(2).unwrap().input_path(true),
+            _ => return Err(format!("unexpected argument {}", arg).into()),
+        }
+    }
+    let contents = read_input(&path).map_err(|e| format!("{}: {}", path.display(), e))?;
+    let inputs = day2::Inputs::parse(&contents).map_err(|e| e.in_file(&path))?;
+    let analysis = inputs.analyze();
+
+    println!("Best possible: {}", analysis.best);
+    println!("Worst possible: {}", analysis.worst);
+    println!();
+    let width = analysis
+        .readings
+        .iter()
+        .flat_map(|(_, rules, _)| rules.ours.iter().map(|&(_, m)| rules.describe(m).len()))
+        .max()
+        .unwrap_or(0);
+    let row = |name: &str, cells: Vec<String>, total: &str| {
+        let cells = cells.iter().map(|c| format!("{:<1$}", c, width));
+        println!(
+            "{:<7}  {}  {:>7}",
+            name,
+            cells.collect::<Vec<_>>().join("  "),
+            total
+        );
+    };
+    let symbols = analysis.readings[0]
+        .1
+        .ours
+        .iter()
+        .map(|(c, _)| c.to_string());
+    row("reading", symbols.collect(), "total");
+    for (name, rules, total) in &analysis.readings {
+        let moves = rules.ours.iter().map(|&(_, m)| rules.describe(m));
+        row(name, moves.collect(), &total.to_string());
+    }
+    let (name, rules, total) = analysis.likely();
+    let moves = rules
+        .ours
+        .iter()
+        .map(|&(c, m)| format!("{}={}", c, rules.describe(m)))
+        .collect::<Vec<_>>();
+    println!();
+    println!(
+        "Most likely meant: {} with {}, scoring {}",
+        name,
+        moves.join(", "),
+        total
+    );
+    Ok(())
+}
+
+fn gen(args: &[String]) -> BoxResult<()> {
+    let mut day = None;
+    let (mut seed, mut size) = (0, 100);
+    let mut it = args.iter();
+    while let Some(arg) = it.next() {
+        match arg.as_str() {
+            "--seed" => {
+                let s = it.next().ok_or("--seed needs a value")?;
+                seed = s.parse().map_err(|_| format!("bad seed {}", s))?;
+            }
+            "--size" => {
+                let s = it.next().ok_or("--size needs a value")?;
+                size = s.parse().map_err(|_| format!("bad size {}", s))?;
+            }
+            s if !s.starts_with('-') && day.is_none() => {
+                day = Some(s.parse().map_err(|_| format!("bad day {}", s))?);
+            }
+            _ => return Err(format!("unexpected argument {}", arg).into()),
+        }
+    }
+    let day = day.ok_or("need a day")?;
+    let input = gen::generate(day, seed, size).ok_or(format!("no such day {}", day))?;
+    print!("{}", input);
+    Ok(())
+}
+
+fn list() {
+    for day in DAYS {
+        let inputs = day
+            .input_files()
+            .iter()
+            .map(|p| p.display().to_string())
+            .collect::<Vec<_>>();
+        let mut line = format!("{:>2}  {:<24} {}", day.number, day.title, inputs.join(" "));
+        if !day.params.is_empty() {
+            line += &format!("  (params: {})", day.params.join(", "));
+        }
+        let variants = day.variants();
+        if !variants.is_empty() {
+            line += &format!("  (variants: {})", variants.join(", "));
+        }
+        println!("{}", line);
+    }
+}
+
+fn main() {
+    let args: Vec<String> = env::args().skip(1).collect();
+    let result = match args.first().map(String::as_str) {
+        Some("run") => RunOpts::parse(&args[1..]).and_then(|opts| opts.run()),
+        Some("bench") => RunOpts::parse(&args[1..]).and_then(|opts| opts.bench()),
+        Some("crosscheck") => RunOpts::parse(&args[1..]).and_then(|opts| opts.crosscheck()),
+        Some("vis") => RunOpts::parse(&args[1..]).and_then(|opts| opts.visualize()),
+        Some("image") => RunOpts::parse(&args[1..]).and_then(|opts| opts.images()),
+        Some("watch") => RunOpts::parse(&args[1..]).and_then(|opts| opts.watch()),
+        Some("calories") => calories(&args[1..]),
+        Some("guide") => guide(&args[1..]),
+        Some("gen") => gen(&args[1..]),
+        Some("verify") => verify(&args[1..]),
+        Some("list") if args.len() == 1 => {
+            list();
+            Ok(())
+        }
+        _ => {
+            eprintln!("{}", USAGE);
+            process::exit(2);
+        }
+    };
+    if let Err(e) = result {
+        eprintln!("error: {}", e);
+        process::exit(1);
+    }
+}
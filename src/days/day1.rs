@@ -0,0 +1,317 @@
+use std::cmp::Reverse;
+use std::collections::BinaryHeap;
+use std::fmt::Write;
+use std::io::BufRead;
+
+use crate::error::parse_part;
+use crate::{BoxResult, Params, ParseError, ParseResult, Solution};
+
+/// Calorie totals per elf, in the order the elves appear.
+pub struct Elves {
+    sums: Vec<i64>,
+    /// Where the input has a blank line that doesn't separate two elves.
+    empty: Vec<usize>,
+    /// How many of the best-stocked elves part 2 adds up.
+    top: usize,
+}
+
+impl Elves {
+    /// A report on how many calories the elves carry, naming the `k` best-stocked, with
+    /// totals split into `buckets` ranges.
+    pub fn stats(&self, k: usize, buckets: usize) -> Stats {
+        let mut sorted = self.sums.clone();
+        sorted.sort_unstable();
+        let n = sorted.len();
+        let total = sorted.iter().map(|&s| s as i128).sum::<i128>();
+        let median = if n % 2 == 1 {
+            sorted[n / 2] as f64
+        } else {
+            (sorted[n / 2 - 1] as i128 + sorted[n / 2] as i128) as f64 / 2.0
+        };
+        // Nearest rank: the smallest total at least `p` percent of elves have no more than.
+        let percentiles = PERCENTILES
+            .iter()
+            .map(|&p| (p, sorted[(p * n).div_ceil(100).max(1) - 1]))
+            .collect();
+        Stats {
+            elves: n,
+            empty: self.empty.clone(),
+            top: self.top_k(k).largest(),
+            total,
+            mean: total as f64 / n as f64,
+            median,
+            percentiles,
+            histogram: histogram(&sorted, buckets.max(1)),
+        }
+    }
+
+    /// The `k` largest totals, as elf indices and totals, largest first.
+    pub fn top_k(&self, k: usize) -> TopK {
+        let mut top = TopK::new(k);
+        for (elf, &sum) in self.sums.iter().enumerate() {
+            top.push(elf, sum);
+        }
+        top
+    }
+}
+
+/// The `k` largest totals seen so far. They're kept in a min-heap, so each new total only has
+/// to beat the smallest of them, and memory doesn't grow with the number of elves.
+#[derive(Debug, Clone)]
+pub struct TopK {
+    k: usize,
+    /// Totals with the index of their elf. Ties go to the earlier elf.
+    heap: BinaryHeap<Reverse<(i64, Reverse<usize>)>>,
+}
+
+impl TopK {
+    pub fn new(k: usize) -> Self {
+        TopK {
+            k,
+            // `k` can be anything, so the heap only grows as totals arrive.
+            heap: BinaryHeap::new(),
+        }
+    }
+
+    pub fn push(&mut self, elf: usize, sum: i64) {
+        self.heap.push(Reverse((sum, Reverse(elf))));
+        if self.heap.len() > self.k {
+            self.heap.pop();
+        }
+    }
+
+    /// Elf indices and their totals, largest first.
+    pub fn largest(&self) -> Vec<(usize, i64)> {
+        let mut top: Vec<_> = self.heap.iter().map(|r| r.0).collect();
+        top.sort_by(|a, b| b.cmp(a));
+        top.into_iter().map(|(sum, elf)| (elf.0, sum)).collect()
+    }
+
+    /// The sum of the kept totals, which can be more than any one total fits in.
+    pub fn total(&self) -> i128 {
+        self.heap.iter().map(|r| r.0 .0 as i128).sum()
+    }
+}
+
+/// The percentiles `Stats` reports.
+const PERCENTILES: [usize; 5] = [10, 25, 75, 90, 99];
+
+/// Summary statistics of the elves' totals.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Stats {
+    pub elves: usize,
+    /// Line numbers of blank lines that don't separate two elves.
+    pub empty: Vec<usize>,
+    /// The best-stocked elves' indices and totals, largest first.
+    pub top: Vec<(usize, i64)>,
+    pub total: i128,
+    pub mean: f64,
+    pub median: f64,
+    pub percentiles: Vec<(usize, i64)>,
+    pub histogram: Vec<Bucket>,
+}
+
+/// How many elves have totals in `min..=max`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Bucket {
+    pub min: i64,
+    pub max: i64,
+    pub count: usize,
+}
+
+/// `sorted` split into up to `buckets` ranges of equal width, covering all of them.
+fn histogram(sorted: &[i64], buckets: usize) -> Vec<Bucket> {
+    let (lo, hi) = (sorted[0] as i128, sorted[sorted.len() - 1] as i128);
+    let width = ((hi - lo + 1) as u128).div_ceil(buckets as u128) as i128;
+    let mut histogram = Vec::new();
+    let mut min = lo;
+    while min <= hi {
+        let max = (min + width - 1).min(hi);
+        let count = sorted.partition_point(|&s| s as i128 <= max)
+            - sorted.partition_point(|&s| (s as i128) < min);
+        histogram.push(Bucket {
+            min: min as i64,
+            max: max as i64,
+            count,
+        });
+        min += width;
+    }
+    histogram
+}
+
+impl Stats {
+    /// The report as a few aligned tables. Elves are numbered from 1, as `aoc calories` lists
+    /// them.
+    pub fn to_table(&self) -> String {
+        let mut out = String::new();
+        let mut row = |name: &str, value: String| writeln!(out, "{:<10} {:>20}", name, value);
+        row("elves", self.elves.to_string()).unwrap();
+        let lines = self.empty.iter().map(|l| l.to_string()).collect::<Vec<_>>();
+        let empty = match lines.len() {
+            0 => "0".to_owned(),
+            n => format!("{} (line {})", n, lines.join(", ")),
+        };
+        row("empty", empty).unwrap();
+        row("total", self.total.to_string()).unwrap();
+        row("mean", format!("{:.1}", self.mean)).unwrap();
+        row("median", format!("{:.1}", self.median)).unwrap();
+        for (p, value) in &self.percentiles {
+            row(&format!("p{}", p), value.to_string()).unwrap();
+        }
+
+        writeln!(out, "\nrank  elf       calories").unwrap();
+        for (rank, (elf, sum)) in self.top.iter().enumerate() {
+            writeln!(out, "{:>4}  {:<6} {:>11}", rank + 1, elf + 1, sum).unwrap();
+        }
+
+        // Bars are scaled so the fullest bucket is 40 characters wide.
+        let most = self.histogram.iter().map(|b| b.count).max().unwrap_or(0);
+        let width = self.histogram.iter().map(|b| b.max.to_string().len()).max();
+        let width = width.unwrap_or(0);
+        writeln!(out, "\n{:>w$}  {:>w$}  elves", "from", "to", w = width).unwrap();
+        for b in &self.histogram {
+            let bar = "#".repeat((b.count * 40).div_ceil(most.max(1)));
+            let line = format!(
+                "{:>w$}  {:>w$}  {:>5} {}",
+                b.min,
+                b.max,
+                b.count,
+                bar,
+                w = width
+            );
+            writeln!(out, "{}", line.trim_end()).unwrap();
+        }
+        out
+    }
+
+    /// The report as one JSON object, with elves numbered from 1.
+    pub fn to_json(&self) -> String {
+        let list = |items: Vec<String>| format!("[{}]", items.join(", "));
+        let empty = list(self.empty.iter().map(|l| l.to_string()).collect());
+        let top = self
+            .top
+            .iter()
+            .map(|(elf, sum)| format!(r#"{{"elf": {}, "calories": {}}}"#, elf + 1, sum));
+        let percentiles = self
+            .percentiles
+            .iter()
+            .map(|(p, value)| format!(r#""p{}": {}"#, p, value))
+            .collect::<Vec<_>>();
+        let histogram = self.histogram.iter().map(|b| {
+            format!(
+                r#"{{"min": {}, "max": {}, "count": {}}}"#,
+                b.min, b.max, b.count
+            )
+        });
+        format!(
+            r#"{{"elves": {}, "empty_groups": {}, "total": {}, "mean": {}, "median": {}, "percentiles": {{{}}}, "top": {}, "histogram": {}}}"#,
+            self.elves,
+            empty,
+            self.total,
+            self.mean,
+            self.median,
+            percentiles.join(", "),
+            list(top.collect()),
+            list(histogram.collect())
+        )
+    }
+}
+
+/// Adds up one elf's snacks at a time from lines of input.
+#[derive(Debug, Default)]
+struct Group {
+    sum: Option<i64>,
+    /// Blank lines since the last group that didn't end one. They only count as empty groups
+    /// once another group follows, so blank lines at the end of the input are fine.
+    blanks: Vec<usize>,
+    /// Line numbers of blank lines between groups that had no snacks of their own.
+    empty: Vec<usize>,
+}
+
+impl Group {
+    /// Take in line number `number`, returning the finished total if it ends a group.
+    fn line(&mut self, line: &str, number: usize) -> ParseResult<Option<i64>> {
+        let calories = line.trim();
+        if calories.is_empty() {
+            if self.sum.is_none() {
+                self.blanks.push(number);
+            }
+            return Ok(self.sum.take());
+        }
+        self.empty.append(&mut self.blanks);
+        // Errors are found within the line, then moved to where it is in the input.
+        let n: i64 = parse_part(line, calories).map_err(|e| e.on_line(number))?;
+        let sum =
+            self.sum.unwrap_or(0).checked_add(n).ok_or_else(|| {
+                ParseError::at(line, calories, "too many calories").on_line(number)
+            })?;
+        self.sum = Some(sum);
+        Ok(None)
+    }
+}
+
+/// The totals from `reader`, read a line at a time so the input never has to fit in memory.
+/// `f` gets each elf's index and total.
+pub fn each_total<R: BufRead>(mut reader: R, mut f: impl FnMut(usize, i64)) -> BoxResult<()> {
+    let mut group = Group::default();
+    let (mut line, mut number, mut elves) = (String::new(), 0, 0);
+    loop {
+        line.clear();
+        let done = reader.read_line(&mut line)? == 0;
+        number += 1;
+        if let Some(sum) = group.line(&line, number)? {
+            f(elves, sum);
+            elves += 1;
+        }
+        if done {
+            break;
+        }
+    }
+    if elves == 0 {
+        return Err("no elves found".into());
+    }
+    Ok(())
+}
+
+/// The `k` largest totals in `reader`, in constant memory however long it is.
+pub fn top_k<R: BufRead>(reader: R, k: usize) -> BoxResult<TopK> {
+    let mut top = TopK::new(k);
+    each_total(reader, |elf, sum| top.push(elf, sum))?;
+    Ok(top)
+}
+
+impl Solution for Elves {
+    type Part1 = i64;
+    type Part2 = i128;
+
+    fn parse(input: &str) -> ParseResult<Self> {
+        let mut sums = Vec::new();
+        let mut group = Group::default();
+        // A blank line after the last one finishes the last group.
+        for (i, line) in input.lines().chain([""]).enumerate() {
+            sums.extend(group.line(line, i + 1)?);
+        }
+        if sums.is_empty() {
+            return Err(ParseError::at_end(input, "no elves found"));
+        }
+        Ok(Elves {
+            sums,
+            empty: group.empty,
+            top: 3,
+        })
+    }
+
+    fn part1(&self) -> i64 {
+        self.top_k(1).largest()[0].1
+    }
+
+    fn part2(&self) -> i128 {
+        self.top_k(self.top).total()
+    }
+
+    const PARAMS: &'static [&'static str] = &["top"];
+
+    fn configure(&mut self, params: &Params) -> Result<(), String> {
+        params.update("top", &mut self.top)
+    }
+}
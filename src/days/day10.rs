@@ -0,0 +1,121 @@
+use crate::error::parse_part;
+use crate::image::Rgb;
+use crate::vis::{Frame, Hidden};
+use crate::{Image, ParseError, ParseResult, Solution, Viewer};
+
+enum Inst {
+    Addx(i32),
+    Noop,
+}
+
+impl Inst {
+    fn run<F: FnMut(i32, i32)>(&self, cycle: &mut i32, x: &mut i32, f: &mut F) {
+        match self {
+            Inst::Noop => {
+                f(*cycle, *x);
+                *cycle += 1;
+            }
+            Inst::Addx(i) => {
+                f(*cycle, *x);
+                f(*cycle + 1, *x);
+                *x += i;
+                *cycle += 2;
+            }
+        }
+    }
+}
+
+pub struct Program(Vec<Inst>);
+
+impl Program {
+    fn run<F: FnMut(i32, i32)>(&self, mut f: F) {
+        let mut cycle = 1;
+        let mut x = 1;
+        for inst in &self.0 {
+            inst.run(&mut cycle, &mut x, &mut f);
+        }
+    }
+
+    fn signal_strength(&self, viewer: &mut dyn Viewer) -> i32 {
+        let mut ret = 0;
+        self.run(|cycle, x| {
+            if cycle % 40 == 20 {
+                ret += cycle * x
+            }
+            viewer.show(&mut || {
+                Frame::new(format!("cycle {}, X = {}", cycle, x))
+                    .text(&format!("signal strength {}", ret))
+            });
+        });
+        ret
+    }
+
+    fn draw_crt(&self, viewer: &mut dyn Viewer) -> String {
+        let mut crt = String::new();
+        self.run(|cycle, x| {
+            let cpix = (cycle - 1) % 40 + 1;
+            if cpix >= x && cpix < x + 3 {
+                crt += "#";
+            } else {
+                crt += ".";
+            }
+            if cpix == 40 {
+                crt += "\n";
+            }
+            viewer.show(&mut || {
+                let sprite = (1..=40)
+                    .map(|c| if c >= x && c < x + 3 { '#' } else { '.' })
+                    .collect::<String>();
+                Frame::new(format!("cycle {}, X = {}", cycle, x))
+                    .text(&format!("sprite {}", sprite))
+                    .text(&crt)
+            });
+        });
+        crt
+    }
+}
+
+impl Solution for Program {
+    type Part1 = i32;
+    type Part2 = String;
+
+    fn parse(input: &str) -> ParseResult<Self> {
+        let insts = input
+            .lines()
+            .map(|line| {
+                if let Some(arg) = line.strip_prefix("addx ") {
+                    Ok(Inst::Addx(parse_part(input, arg)?))
+                } else if line == "noop" {
+                    Ok(Inst::Noop)
+                } else {
+                    Err(ParseError::at(input, line, "unknown instruction"))
+                }
+            })
+            .collect::<ParseResult<_>>()?;
+        Ok(Program(insts))
+    }
+
+    fn part1(&self) -> i32 {
+        self.signal_strength(&mut Hidden)
+    }
+
+    fn part2(&self) -> String {
+        self.draw_crt(&mut Hidden)
+    }
+
+    fn visualize(&self, part: u32, viewer: &mut dyn Viewer) -> Option<String> {
+        if part == 1 {
+            Some(self.signal_strength(viewer).to_string())
+        } else {
+            Some(self.draw_crt(viewer))
+        }
+    }
+
+    fn pictures(&self) -> Vec<(&'static str, Image)> {
+        let crt = Image::from_text(&self.draw_crt(&mut Hidden), |c| match c {
+            '#' => Rgb(120, 255, 120),
+            _ => Rgb(10, 30, 10),
+        });
+        vec![("crt", crt)]
+    }
+}
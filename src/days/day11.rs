@@ -0,0 +1,229 @@
+use regex::Regex;
+
+use crate::error::parse_part;
+use crate::{Params, ParseError, ParseResult, Solution};
+
+#[derive(Debug, Clone)]
+enum Operand {
+    Value,
+    Imm(u64),
+}
+
+impl Operand {
+    fn eval(&self, v: u64) -> u64 {
+        match self {
+            Operand::Value => v,
+            Operand::Imm(i) => *i,
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+enum Op {
+    Plus(Operand),
+    Times(Operand),
+}
+
+impl Op {
+    fn eval(&self, v: u64) -> u64 {
+        match self {
+            Op::Plus(o) => v + o.eval(v),
+            Op::Times(o) => v * o.eval(v),
+        }
+    }
+
+    fn parse(contents: &str, opstr: &str, operandstr: &str) -> ParseResult<Op> {
+        let operand = if operandstr == "old" {
+            Operand::Value
+        } else {
+            Operand::Imm(parse_part(contents, operandstr)?)
+        };
+        if opstr == "+" {
+            Ok(Op::Plus(operand))
+        } else if opstr == "*" {
+            Ok(Op::Times(operand))
+        } else {
+            Err(ParseError::at(contents, opstr, "unknown op"))
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+struct Monkey {
+    items: Vec<u64>,
+    op: Op,
+    test_div: u64,
+    target_true: usize,
+    target_false: usize,
+    inspected: usize,
+}
+
+struct Throw {
+    item: u64,
+    target: usize,
+}
+
+type Transform = Box<dyn Fn(u64) -> u64>;
+
+struct Monkeys {
+    monkeys: Vec<Monkey>,
+    transform: Transform,
+}
+
+impl Monkey {
+    fn turn(&mut self, transform: &dyn Fn(u64) -> u64) -> Vec<Throw> {
+        let mut throws = Vec::new();
+        for item in &self.items {
+            let v = transform(self.op.eval(*item));
+            let target = if v.is_multiple_of(self.test_div) {
+                self.target_true
+            } else {
+                self.target_false
+            };
+            throws.push(Throw { item: v, target });
+            self.inspected += 1;
+        }
+        self.items.clear();
+        throws
+    }
+}
+
+impl Monkeys {
+    fn round(&mut self) {
+        for idx in 0..self.monkeys.len() {
+            for throw in self.monkeys[idx].turn(&self.transform) {
+                self.monkeys[throw.target].items.push(throw.item);
+            }
+        }
+    }
+
+    fn run(ms: &[Monkey], rounds: usize, gen_transform: impl Fn(&[Monkey]) -> Transform) -> u64 {
+        let transform = gen_transform(ms);
+        let mut monkeys = Monkeys {
+            monkeys: ms.to_vec(),
+            transform,
+        };
+        for _ in 0..rounds {
+            monkeys.round();
+        }
+        let mut inspected = monkeys
+            .monkeys
+            .iter()
+            .map(|m| m.inspected as u64)
+            .collect::<Vec<_>>();
+        inspected.sort();
+        inspected.reverse();
+        inspected[0] * inspected[1]
+    }
+
+    /// Keep worry levels below the product of the tests' divisors, which every test still
+    /// sees the same way.
+    fn modulo_tests(monkeys: &[Monkey]) -> Transform {
+        let modulus: u64 = monkeys.iter().map(|m| m.test_div).product();
+        Box::new(move |v| v % modulus)
+    }
+}
+
+/// The monkeys as described in the notes, before any rounds are played.
+pub struct Notes {
+    monkeys: Vec<Monkey>,
+    part1_rounds: usize,
+    part2_rounds: usize,
+    /// What worry levels are divided by after each inspection in part 1.
+    relief: u64,
+}
+
+impl Solution for Notes {
+    type Part1 = u64;
+    type Part2 = u64;
+
+    fn parse(contents: &str) -> ParseResult<Self> {
+        let mut ret = Vec::new();
+        let re = Regex::new(
+            r"\A\s*Monkey \d+:
+  Starting items: (?P<items>(?:\d+,\s)*\d+)
+  Operation: new = old (?P<op>[+*]) (?P<operand>\d+|old)
+  Test: divisible by (?P<div>\d+)
+    If true: throw to monkey (?P<target_true>\d+)
+    If false: throw to monkey (?P<target_false>\d+)\s*\z",
+        )
+        .unwrap();
+        let mut targets = Vec::new();
+        for block in contents.split("\n\n").filter(|b| !b.trim().is_empty()) {
+            let cap = re.captures(block).ok_or_else(|| {
+                ParseError::at(
+                    contents,
+                    block.trim_start(),
+                    "expected a monkey description",
+                )
+            })?;
+            let field = |name: &str| cap.name(name).unwrap().as_str();
+            let items = field("items")
+                .split(", ")
+                .map(|i| parse_part(contents, i))
+                .collect::<ParseResult<_>>()?;
+            let test_div = parse_part(contents, field("div"))?;
+            if test_div == 0 {
+                return Err(ParseError::at(
+                    contents,
+                    field("div"),
+                    "can't divide by zero",
+                ));
+            }
+            let monkey = Monkey {
+                items,
+                op: Op::parse(contents, field("op"), field("operand"))?,
+                test_div,
+                target_true: parse_part(contents, field("target_true"))?,
+                target_false: parse_part(contents, field("target_false"))?,
+                inspected: 0,
+            };
+            targets.push((field("target_true"), monkey.target_true));
+            targets.push((field("target_false"), monkey.target_false));
+            ret.push(monkey);
+        }
+
+        // Targets can only be checked once all the monkeys are known.
+        for (s, target) in targets {
+            if target >= ret.len() {
+                return Err(ParseError::at(contents, s, format!("no monkey {}", target)));
+            }
+        }
+        if ret.len() < 2 {
+            return Err(ParseError::at_end(contents, "need at least two monkeys"));
+        }
+        Ok(Notes {
+            monkeys: ret,
+            part1_rounds: 20,
+            part2_rounds: 10_000,
+            relief: 3,
+        })
+    }
+
+    fn part1(&self) -> u64 {
+        let relief = self.relief;
+        Monkeys::run(&self.monkeys, self.part1_rounds, |monkeys| {
+            // Without relief, worry levels grow as in part 2 and need keeping down the same way.
+            if relief == 1 {
+                return Monkeys::modulo_tests(monkeys);
+            }
+            Box::new(move |v| v / relief)
+        })
+    }
+
+    fn part2(&self) -> u64 {
+        Monkeys::run(&self.monkeys, self.part2_rounds, Monkeys::modulo_tests)
+    }
+
+    const PARAMS: &'static [&'static str] = &["part1_rounds", "part2_rounds", "relief"];
+
+    fn configure(&mut self, params: &Params) -> Result<(), String> {
+        params.update("part1_rounds", &mut self.part1_rounds)?;
+        params.update("part2_rounds", &mut self.part2_rounds)?;
+        params.update("relief", &mut self.relief)?;
+        if self.relief == 0 {
+            return Err("relief can't be zero".to_owned());
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,131 @@
+use std::collections::VecDeque;
+
+use crate::geometry::Point;
+use crate::image::Rgb;
+use crate::{grid, Image, ParseError, ParseResult, Solution};
+
+type Val = u32;
+
+pub struct Grid {
+    heights: grid::Grid<Val>,
+    start: Point,
+    end: Point,
+}
+
+impl Grid {
+    /// The squares that can step to `p`.
+    fn reverse_adjacent(&self, p: Point) -> impl Iterator<Item = Point> + '_ {
+        let h = self.heights[p];
+        self.heights
+            .neighbours4(p)
+            .filter(move |&o| self.heights[o] + 1 >= h)
+    }
+
+    /// The shortest path to `start` from the nearest square matching `f`, found by searching
+    /// backwards from `start`, if any square matching `f` can get there.
+    fn reverse_djikstra<F: Fn(Point) -> bool>(&self, start: Point, f: F) -> Option<Vec<Point>> {
+        // Where each square steps to on its way to `start`.
+        let mut next = grid::Grid::new(self.heights.width(), self.heights.height(), None);
+        let mut todo = VecDeque::new();
+
+        next[start] = Some(start);
+        todo.push_back(start);
+
+        while let Some(p) = todo.pop_front() {
+            for a in self.reverse_adjacent(p) {
+                if f(a) {
+                    let mut path = vec![a, p];
+                    while path[path.len() - 1] != start {
+                        path.push(next[path[path.len() - 1]].unwrap());
+                    }
+                    return Some(path);
+                } else if next[a].is_none() {
+                    next[a] = Some(p);
+                    todo.push_back(a);
+                }
+            }
+        }
+        None
+    }
+
+    /// The shortest path from `S` to `E`, which parsing checks there is.
+    fn path1(&self) -> Vec<Point> {
+        self.reverse_djikstra(self.end, |p| p == self.start)
+            .unwrap()
+    }
+
+    /// The shortest path to `E` from any lowest square. `S` is one of those, so there is one.
+    fn path2(&self) -> Vec<Point> {
+        self.reverse_djikstra(self.end, |p| self.heights[p] == 'a' as Val)
+            .unwrap()
+    }
+
+    fn steps(path: Vec<Point>) -> u32 {
+        path.len() as u32 - 1
+    }
+
+    /// The hills, low green to high white, with `path` in red.
+    fn picture(&self, path: &[Point]) -> Image {
+        let (low, high) = (Rgb(30, 90, 40), Rgb(240, 240, 230));
+        let mut image = Image::from_grid(&self.heights, |_, &h| {
+            low.mix(high, (h - 'a' as Val) as f64 / 25.0)
+        });
+        for p in path {
+            image.set(p.x as usize, p.y as usize, Rgb::RED);
+        }
+        image
+    }
+}
+
+impl Solution for Grid {
+    type Part1 = u32;
+    type Part2 = u32;
+
+    fn parse(contents: &str) -> ParseResult<Self> {
+        let mut start = None;
+        let mut end = None;
+        let heights = grid::Grid::parse(contents, |p, c| {
+            let c = match c {
+                'S' => {
+                    start = Some(p);
+                    'a'
+                }
+                'E' => {
+                    end = Some(p);
+                    'z'
+                }
+                c if c.is_ascii_lowercase() => c,
+                _ => return Err("expected a letter from a to z"),
+            };
+            Ok(c as Val)
+        })?;
+        let start = start.ok_or_else(|| ParseError::at_end(contents, "no start S found"))?;
+        let end = end.ok_or_else(|| ParseError::at_end(contents, "no end E found"))?;
+        let grid = Grid {
+            heights,
+            start,
+            end,
+        };
+        if grid.reverse_djikstra(end, |p| p == start).is_none() {
+            let s = contents.rfind('S').unwrap();
+            return Err(ParseError::at_offset(contents, s, "no path from S to E"));
+        }
+        Ok(grid)
+    }
+
+    fn part1(&self) -> u32 {
+        Grid::steps(self.path1())
+    }
+
+    fn part2(&self) -> u32 {
+        Grid::steps(self.path2())
+    }
+
+    fn pictures(&self) -> Vec<(&'static str, Image)> {
+        let (part1, part2) = (self.path1(), self.path2());
+        vec![
+            ("part1", self.picture(&part1)),
+            ("part2", self.picture(&part2)),
+        ]
+    }
+}
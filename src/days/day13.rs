@@ -0,0 +1,235 @@
+use std::cmp::Ordering;
+use std::collections::BTreeSet;
+use std::fmt;
+use std::iter::Peekable;
+use std::str::CharIndices;
+
+use crate::{ParseError, ParseResult, Solution};
+
+#[derive(Debug, Eq)]
+enum Val {
+    Int(i32),
+    List(Vec<Val>),
+}
+
+impl Val {
+    fn cmp_list(vec1: &[Val], vec2: &[Val]) -> Ordering {
+        let (mut it1, mut it2) = (vec1.iter(), vec2.iter());
+        loop {
+            match (it1.next(), it2.next()) {
+                (Some(v1), Some(v2)) => {
+                    let ord = v1.cmp(v2);
+                    if ord.is_ne() {
+                        return ord;
+                    }
+                }
+                (None, Some(_)) => return Ordering::Less,
+                (Some(_), None) => return Ordering::Greater,
+                (None, None) => return Ordering::Equal,
+            }
+        }
+    }
+}
+
+impl Ord for Val {
+    fn cmp(&self, other: &Self) -> Ordering {
+        match (self, other) {
+            (Val::Int(i1), Val::Int(i2)) => i1.cmp(i2),
+            (Val::List(l1), Val::List(l2)) => Val::cmp_list(l1, l2),
+            (Val::Int(i1), Val::List(l2)) => Val::cmp_list(&[Val::Int(*i1)], l2),
+            (Val::List(l1), Val::Int(i2)) => Val::cmp_list(l1, &[Val::Int(*i2)]),
+        }
+    }
+}
+
+impl PartialOrd for Val {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl PartialEq for Val {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl fmt::Display for Val {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Val::Int(i) => write!(f, "{}", i),
+            Val::List(l) => {
+                f.write_str("[")?;
+                for (i, v) in l.iter().enumerate() {
+                    if i > 0 {
+                        f.write_str(",")?;
+                    }
+                    write!(f, "{}", v)?;
+                }
+                f.write_str("]")
+            }
+        }
+    }
+}
+
+#[derive(Debug, PartialEq)]
+pub struct Input(Vec<(Val, Val)>);
+
+/// Prints the pairs as they appear in the puzzle input.
+impl fmt::Display for Input {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        for (i, (a, b)) in self.0.iter().enumerate() {
+            if i > 0 {
+                writeln!(f)?;
+            }
+            writeln!(f, "{}\n{}", a, b)?;
+        }
+        Ok(())
+    }
+}
+
+/// How deep lists can nest. Parsing, comparing and dropping packets all recurse, so without a
+/// limit a long enough run of `[` overflows the stack.
+const MAX_DEPTH: usize = 256;
+
+/// Reads packets, keeping track of where it is for error messages.
+struct Parser<'a> {
+    input: &'a str,
+    it: Peekable<CharIndices<'a>>,
+    depth: usize,
+}
+
+impl<'a> Parser<'a> {
+    fn new(input: &'a str) -> Self {
+        Parser {
+            input,
+            it: input.char_indices().peekable(),
+            depth: 0,
+        }
+    }
+
+    fn peek(&mut self) -> Option<char> {
+        self.it.peek().map(|(_, c)| *c)
+    }
+
+    fn offset(&mut self) -> usize {
+        self.it.peek().map_or(self.input.len(), |(i, _)| *i)
+    }
+
+    fn error(&mut self, message: &str) -> ParseError {
+        ParseError::at_offset(self.input, self.offset(), message)
+    }
+
+    fn expect(&mut self, c: char) -> ParseResult<()> {
+        if self.peek() == Some(c) {
+            self.it.next();
+            Ok(())
+        } else {
+            Err(self.error(&format!("expected {:?}", c)))
+        }
+    }
+
+    fn parse_int(&mut self) -> ParseResult<i32> {
+        let start = self.offset();
+        while self.peek().is_some_and(|c| c.is_ascii_digit()) {
+            self.it.next();
+        }
+        let s = &self.input[start..self.offset()];
+        if s.is_empty() {
+            return Err(self.error("expected a number or a list"));
+        }
+        s.parse()
+            .map_err(|e| ParseError::at_offset(self.input, start, format!("{}", e)))
+    }
+
+    fn parse_list(&mut self) -> ParseResult<Vec<Val>> {
+        if self.depth == MAX_DEPTH {
+            return Err(self.error("lists are nested too deeply"));
+        }
+        self.expect('[')?;
+        self.depth += 1;
+        let mut ret = Vec::new();
+        while self.peek() != Some(']') {
+            ret.push(self.parse_val()?);
+            match self.peek() {
+                Some(',') => {
+                    self.it.next();
+                }
+                Some(']') => (),
+                _ => return Err(self.error("expected ',' or ']'")),
+            }
+        }
+        self.expect(']')?;
+        self.depth -= 1;
+        Ok(ret)
+    }
+
+    fn parse_val(&mut self) -> ParseResult<Val> {
+        if self.peek() == Some('[') {
+            Ok(Val::List(self.parse_list()?))
+        } else {
+            Ok(Val::Int(self.parse_int()?))
+        }
+    }
+
+    fn parse_line(&mut self) -> ParseResult<Val> {
+        let v = self.parse_val()?;
+        if self.peek().is_some() {
+            self.expect('\n')?;
+        }
+        Ok(v)
+    }
+
+    fn parse_pair(&mut self) -> ParseResult<(Val, Val)> {
+        let a = self.parse_line()?;
+        let b = self.parse_line()?;
+        while self.peek() == Some('\n') {
+            self.it.next();
+        }
+        Ok((a, b))
+    }
+}
+
+impl Solution for Input {
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(contents: &str) -> ParseResult<Self> {
+        let mut parser = Parser::new(contents);
+        let mut pairs = Vec::new();
+        while parser.peek().is_some() {
+            let pair = parser.parse_pair()?;
+            pairs.push(pair);
+        }
+        Ok(Input(pairs))
+    }
+
+    fn part1(&self) -> usize {
+        let mut sum = 0;
+        for (i, (a, b)) in self.0.iter().enumerate() {
+            if a <= b {
+                sum += i + 1;
+            }
+        }
+        sum
+    }
+
+    fn part2(&self) -> usize {
+        let divs = ["[[2]]", "[[6]]"]
+            .iter()
+            .map(|s| Parser::new(s).parse_val().unwrap())
+            .collect::<BTreeSet<_>>();
+        let mut all = self
+            .0
+            .iter()
+            .flat_map(|(a, b)| vec![a, b])
+            .chain(divs.iter())
+            .collect::<Vec<_>>();
+        all.sort();
+        all.iter()
+            .enumerate()
+            .filter(|(_, v)| divs.contains(v))
+            .map(|(i, _)| i + 1)
+            .product()
+    }
+}
@@ -0,0 +1,277 @@
+use std::fmt;
+
+use crate::error::parse_part;
+use crate::geometry::{Bounds, Point, Vec2};
+use crate::grid::Grid;
+use crate::image::Rgb;
+use crate::vis::{Frame, Hidden};
+use crate::{Image, ParseError, ParseResult, Solution, Variant, Viewer};
+
+const SOURCE: Point = Point::new(500, 0);
+
+/// The deepest rock we'll make room for; the cave's cells grow with the square of its depth.
+const MAX_DEPTH: i32 = 2000;
+
+/// Where sand tries to go next, in order of preference.
+const DROPS: [Vec2; 3] = [Vec2::new(0, 1), Vec2::new(-1, 1), Vec2::new(1, 1)];
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Cell {
+    Air,
+    Rock,
+    Sand,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Cave {
+    /// The rock paths from the scan.
+    paths: Vec<Vec<Point>>,
+    /// Everything sand can reach, with `left` as the x coordinate of the first column.
+    cells: Grid<Cell>,
+    left: i32,
+    bottom: i32,
+    has_abyss: bool,
+}
+
+impl Cave {
+    fn new(paths: Vec<Vec<Point>>, bottom: i32) -> Self {
+        // Sand spreads at most one column sideways per row, and stops on the floor two below
+        // the lowest rock.
+        let spread = bottom + 2;
+        let width = (2 * spread + 1) as usize;
+        Cave {
+            paths,
+            cells: Grid::new(width, spread as usize, Cell::Air),
+            left: SOURCE.x - spread,
+            bottom,
+            has_abyss: true,
+        }
+    }
+
+    fn cell(&mut self, p: Point) -> Option<&mut Cell> {
+        self.cells.get_mut(Point::new(p.x - self.left, p.y))
+    }
+
+    fn is_free(&self, p: Point) -> bool {
+        self.cells
+            .get(Point::new(p.x - self.left, p.y))
+            .is_none_or(|&c| c == Cell::Air)
+    }
+
+    fn draw(&mut self, p1: Point, p2: Point) {
+        // Rock sand can never reach doesn't matter, so don't walk far outside the cave.
+        let (min_x, max_x) = (self.left - 1, self.left + self.cells.width() as i32);
+        let clip = |p: Point| Point::new(p.x.clamp(min_x, max_x), p.y);
+        let (p1, p2) = (clip(p1), clip(p2));
+        let mut p = p1;
+        loop {
+            if let Some(c) = self.cell(p) {
+                *c = Cell::Rock;
+            }
+            if p == p2 {
+                break;
+            }
+            p = p.step_towards(p2);
+        }
+    }
+
+    fn do_drop(&mut self) -> Option<Point> {
+        let mut p = SOURCE;
+        while let Some(found) = DROPS.iter().map(|&s| p + s).find(|&o| self.is_free(o)) {
+            if p.y > self.bottom {
+                if self.has_abyss {
+                    return None;
+                } else {
+                    break;
+                }
+            }
+            p = found;
+        }
+        *self.cell(p).unwrap() = Cell::Sand;
+        Some(p)
+    }
+
+    /// Drop grains until one falls into the abyss or one comes to rest at the source, and count
+    /// the ones that come to rest.
+    fn fill(&self, has_abyss: bool, viewer: &mut dyn Viewer) -> usize {
+        self.filled(has_abyss, viewer).1
+    }
+
+    /// The cave after `fill`, and how much sand it holds.
+    fn filled(&self, has_abyss: bool, viewer: &mut dyn Viewer) -> (Cave, usize) {
+        let mut cave = self.clone();
+        cave.has_abyss = has_abyss;
+        let mut dropped = 0;
+        while let Some(p) = cave.do_drop() {
+            dropped += 1;
+            viewer.show(&mut || cave.frame(format!("grain {} rests at {}", dropped, p)));
+            // Rock can catch all the sand before any reaches the abyss.
+            if p == SOURCE {
+                break;
+            }
+        }
+        (cave, dropped)
+    }
+
+    fn picture(&self) -> Image {
+        Image::from_grid(&self.cells, |p, c| match c {
+            _ if p == SOURCE - Point::new(self.left, 0) => Rgb::RED,
+            Cell::Air => Rgb(15, 15, 30),
+            Cell::Rock => Rgb(110, 110, 110),
+            Cell::Sand => Rgb(230, 200, 90),
+        })
+    }
+
+    /// The cave, cropped to the columns with rock or sand in them.
+    fn frame(&self, caption: String) -> Frame {
+        let filled = self
+            .cells
+            .positions()
+            .filter(|&p| self.cells[p] != Cell::Air)
+            .map(|p| Point::new(p.x + self.left, p.y));
+        let mut bounds = Bounds::of(filled).unwrap_or(Bounds::around(SOURCE));
+        bounds = bounds.including(SOURCE);
+        bounds.max.y = self.bottom + 1;
+        Frame::new(caption).area(bounds, |p| {
+            match self.cells.get(Point::new(p.x - self.left, p.y)) {
+                _ if p == SOURCE && self.is_free(p) => '+',
+                Some(Cell::Rock) => '#',
+                Some(Cell::Sand) => 'o',
+                _ => '.',
+            }
+        })
+    }
+
+    /// Both parts by remembering the path the last grain took: the next one follows it to just
+    /// above where that one came to rest, instead of starting again from the source.
+    fn fill_backtracking(&self, has_abyss: bool) -> usize {
+        let mut cave = self.clone();
+        let mut path = vec![SOURCE];
+        let mut rested = 0;
+        while let Some(&p) = path.last() {
+            match DROPS.iter().map(|&s| p + s).find(|&o| cave.is_free(o)) {
+                Some(next) if p.y <= self.bottom => path.push(next),
+                Some(_) if has_abyss => break,
+                _ => {
+                    *cave.cell(p).unwrap() = Cell::Sand;
+                    rested += 1;
+                    path.pop();
+                }
+            }
+        }
+        rested
+    }
+
+    fn part1_backtracking(&self) -> usize {
+        self.fill_backtracking(true)
+    }
+
+    fn part2_backtracking(&self) -> usize {
+        self.fill_backtracking(false)
+    }
+
+    /// Part 2 without dropping any grains: with a floor, sand ends up in every cell it can
+    /// reach, which is any air below one of the three cells above it that has sand.
+    fn part2_flood(&self) -> usize {
+        let width = self.cells.width();
+        let mut row = vec![false; width];
+        row[(SOURCE.x - self.left) as usize] = true;
+        let mut count = 1;
+        for y in 1..self.cells.height() {
+            row = (0..width)
+                .map(|x| {
+                    let mut above = x.saturating_sub(1)..=(x + 1).min(width - 1);
+                    above.any(|a| row[a]) && self.cells[Point::new(x as i32, y as i32)] == Cell::Air
+                })
+                .collect();
+            count += row.iter().filter(|&&sand| sand).count();
+        }
+        count
+    }
+}
+
+/// Prints the rock paths as they appear in the puzzle input.
+impl fmt::Display for Cave {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        for path in &self.paths {
+            let points = path.iter().map(|p| p.to_string()).collect::<Vec<_>>();
+            writeln!(f, "{}", points.join(" -> "))?;
+        }
+        Ok(())
+    }
+}
+
+impl Solution for Cave {
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(contents: &str) -> ParseResult<Self> {
+        let mut paths = Vec::new();
+        for line in contents.lines() {
+            let parts: Vec<&str> = line.split(" -> ").collect();
+            let coords = parts
+                .iter()
+                .map(|c| parse_part(contents, c))
+                .collect::<ParseResult<Vec<Point>>>()?;
+            for (p, part) in coords.iter().zip(&parts) {
+                if !(0..=MAX_DEPTH).contains(&p.y) {
+                    let msg = format!("y must be between 0 and {}", MAX_DEPTH);
+                    return Err(ParseError::at(contents, part, msg));
+                }
+            }
+            for i in 0..coords.len() - 1 {
+                let (p1, p2) = (coords[i], coords[i + 1]);
+                if p1.x != p2.x && p1.y != p2.y {
+                    let msg = "paths must be horizontal or vertical";
+                    return Err(ParseError::at(contents, parts[i + 1], msg));
+                }
+            }
+            paths.push(coords);
+        }
+
+        let bottom = Bounds::of(paths.iter().flatten().copied()).map_or(0, |b| b.max.y);
+        let mut cave = Cave::new(paths.clone(), bottom);
+        for path in &paths {
+            for pair in path.windows(2) {
+                cave.draw(pair[0], pair[1]);
+            }
+        }
+        Ok(cave)
+    }
+
+    fn part1(&self) -> usize {
+        self.fill(true, &mut Hidden)
+    }
+
+    fn part2(&self) -> usize {
+        self.fill(false, &mut Hidden)
+    }
+
+    fn visualize(&self, part: u32, viewer: &mut dyn Viewer) -> Option<String> {
+        Some(self.fill(part == 1, viewer).to_string())
+    }
+
+    fn pictures(&self) -> Vec<(&'static str, Image)> {
+        vec![
+            ("part1", self.filled(true, &mut Hidden).0.picture()),
+            ("part2", self.filled(false, &mut Hidden).0.picture()),
+        ]
+    }
+
+    fn variants() -> Vec<Variant<Self>> {
+        vec![
+            Variant {
+                name: "backtracking",
+                part1: Some(Cave::part1_backtracking),
+                part2: Some(Cave::part2_backtracking),
+                handles: |_| true,
+            },
+            Variant {
+                name: "flood",
+                part1: None,
+                part2: Some(Cave::part2_flood),
+                handles: |_| true,
+            },
+        ]
+    }
+}
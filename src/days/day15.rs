@@ -0,0 +1,213 @@
+use regex::Regex;
+use std::ops::Range;
+
+use crate::error::parse_part;
+use crate::geometry::Point;
+use crate::{Params, ParseError, ParseResult, Solution, Variant};
+
+struct Sensor {
+    pos: Point,
+    beacon: Point,
+}
+
+impl Sensor {
+    fn parse(contents: &str, line: &str) -> ParseResult<Self> {
+        let re = Regex::new(
+            r"Sensor at x=(-?\d+), y=(-?\d+): closest beacon is at x=(-?\d+), y=(-?\d+)",
+        )
+        .unwrap();
+        let caps = re
+            .captures(line)
+            .ok_or_else(|| ParseError::at(contents, line, "expected a sensor and beacon"))?;
+        let num = |i| parse_part(contents, caps.get(i).unwrap().as_str());
+        Ok(Sensor {
+            pos: Point::new(num(1)?, num(2)?),
+            beacon: Point::new(num(3)?, num(4)?),
+        })
+    }
+
+    fn radius(&self) -> i32 {
+        self.pos.manhattan_to(self.beacon)
+    }
+
+    fn covers(&self, p: Point) -> bool {
+        self.pos.manhattan_to(p) <= self.radius()
+    }
+
+    /// The points just out of the sensor's reach.
+    fn perimeter(&self) -> impl Iterator<Item = Point> + '_ {
+        let r = self.radius() + 1;
+        (0..r).flat_map(move |i| {
+            let (x, y) = (self.pos.x, self.pos.y);
+            [
+                Point::new(x + i, y - r + i),
+                Point::new(x + r - i, y + i),
+                Point::new(x - i, y + r - i),
+                Point::new(x - r + i, y - i),
+            ]
+        })
+    }
+
+    fn range_at(&self, y: i32) -> Option<Range<i32>> {
+        let beacon_dist = self.pos.manhattan_to(self.beacon);
+        let y_dist = (y - self.pos.y).abs();
+        let x_dist = beacon_dist - y_dist;
+        if x_dist < 0 {
+            None
+        } else {
+            Some((self.pos.x - x_dist)..(self.pos.x + x_dist + 1))
+        }
+    }
+}
+
+pub struct Input {
+    sensors: Vec<Sensor>,
+    row: i32,
+    max: i32,
+}
+
+impl Input {
+    fn xranges(&self, y: i32) -> Vec<Range<i32>> {
+        let mut xmax = i32::MIN;
+        let mut ranges = self
+            .sensors
+            .iter()
+            .filter_map(|s| s.range_at(y))
+            .collect::<Vec<_>>();
+        let mut ret = Vec::new();
+        ranges.sort_by_key(|r| r.start);
+        for rng in ranges {
+            let clamped = xmax.max(rng.start)..rng.end;
+            if !clamped.is_empty() {
+                ret.push(clamped);
+            }
+            xmax = xmax.max(rng.end);
+        }
+        ret
+    }
+
+    pub fn part1(&self, y: i32) -> usize {
+        let mut beacons_at_y = self
+            .sensors
+            .iter()
+            .map(|s| s.beacon)
+            .filter(|b| b.y == y)
+            .map(|b| b.x)
+            .collect::<Vec<_>>();
+        beacons_at_y.dedup();
+
+        let mut found = 0;
+        for r in self.xranges(y) {
+            found += r.len() - beacons_at_y.iter().filter(|b| r.contains(b)).count();
+        }
+        found
+    }
+
+    /// The tuning frequency of the first point no sensor covers with both coordinates in
+    /// `0..=max`, if there is one.
+    pub fn part2(&self, max: i32) -> Option<i64> {
+        for y in 0..=max {
+            // The ranges are sorted and don't overlap, so the first one not to reach `x` leaves
+            // it uncovered.
+            let mut x = 0;
+            for r in self.xranges(y) {
+                if r.start > x || x > max {
+                    break;
+                }
+                x = x.max(r.end);
+            }
+            if x <= max {
+                return Some(tuning(Point::new(x, y)));
+            }
+        }
+        None
+    }
+
+    /// Part 1 by checking every point on the row.
+    fn part1_brute_force(&self) -> usize {
+        let left = self.sensors.iter().map(|s| s.pos.x - s.radius()).min();
+        let right = self.sensors.iter().map(|s| s.pos.x + s.radius()).max();
+        let (Some(left), Some(right)) = (left, right) else {
+            return 0;
+        };
+        (left..=right)
+            .map(|x| Point::new(x, self.row))
+            .filter(|&p| self.sensors.iter().all(|s| s.beacon != p))
+            .filter(|&p| self.sensors.iter().any(|s| s.covers(p)))
+            .count()
+    }
+
+    /// Part 2 by walking around each sensor's edge: a single uncovered point must sit just
+    /// outside some sensor's reach.
+    fn part2_perimeter(&self) -> String {
+        let bounds = 0..=self.max;
+        let found = self
+            .sensors
+            .iter()
+            .flat_map(|s| s.perimeter())
+            .filter(|p| bounds.contains(&p.x) && bounds.contains(&p.y))
+            .find(|&p| !self.sensors.iter().any(|s| s.covers(p)));
+        answer(found.map(tuning))
+    }
+}
+
+fn tuning(p: Point) -> i64 {
+    p.x as i64 * 4_000_000 + p.y as i64
+}
+
+/// A part 2 answer, which doesn't exist if the sensors cover everything.
+fn answer(frequency: Option<i64>) -> String {
+    frequency.map_or_else(|| "no uncovered point".to_owned(), |f| f.to_string())
+}
+
+impl Solution for Input {
+    type Part1 = usize;
+    type Part2 = String;
+
+    fn parse(contents: &str) -> ParseResult<Self> {
+        let sensors: Vec<Sensor> = contents
+            .lines()
+            .map(|l| Sensor::parse(contents, l))
+            .collect::<ParseResult<_>>()?;
+        if sensors.is_empty() {
+            return Err(ParseError::at_end(contents, "no sensors found"));
+        }
+        Ok(Input {
+            sensors,
+            row: 2_000_000,
+            max: 4_000_000,
+        })
+    }
+
+    fn part1(&self) -> usize {
+        Input::part1(self, self.row)
+    }
+
+    fn part2(&self) -> String {
+        answer(Input::part2(self, self.max))
+    }
+
+    const PARAMS: &'static [&'static str] = &["row", "max"];
+
+    fn configure(&mut self, params: &Params) -> Result<(), String> {
+        params.update("row", &mut self.row)?;
+        params.update("max", &mut self.max)
+    }
+
+    fn variants() -> Vec<Variant<Self>> {
+        vec![
+            Variant {
+                name: "brute-force",
+                part1: Some(Input::part1_brute_force),
+                part2: None,
+                handles: |_| true,
+            },
+            Variant {
+                name: "perimeter",
+                part1: None,
+                part2: Some(Input::part2_perimeter),
+                handles: |_| true,
+            },
+        ]
+    }
+}
@@ -0,0 +1,331 @@
+use regex::Regex;
+use std::collections::{HashMap, HashSet, VecDeque};
+
+use crate::error::parse_part;
+use crate::{Params, ParseError, ParseResult, Solution, Variant};
+
+#[derive(Clone, Debug)]
+struct Tunnel {
+    target: String,
+    len: i32,
+}
+
+#[derive(Clone, Debug)]
+struct Valve {
+    name: String,
+    rate: i32,
+    tunnels: Vec<Tunnel>,
+}
+
+const START: &str = "AA";
+
+/// The most valves worth opening the bitmask variant takes on, which needs a table with an
+/// entry for every set of them.
+const MAX_BITMASK_VALVES: usize = 20;
+
+type Valves = HashMap<String, Valve>;
+
+/// Someone opening valves: where they are and how long they have left.
+#[derive(Clone)]
+struct Opener {
+    turns_left: i32,
+    valve: String,
+}
+
+struct State {
+    opened: HashSet<String>,
+    positions: Vec<Opener>,
+    score: i32,
+}
+
+struct Move {
+    idx: usize,
+    pos: Opener,
+}
+
+#[derive(Debug)]
+pub struct Input {
+    /// Every valve, as described in the input.
+    all: Valves,
+    /// Just the start and the valves worth opening, with tunnels straight between them.
+    valves: Valves,
+    start: String,
+    part1_minutes: i32,
+    part2_minutes: i32,
+}
+
+impl Input {
+    fn simplified_valve(&self, valve: &Valve) -> Option<Valve> {
+        if valve.rate == 0 && valve.name != self.start {
+            return None;
+        }
+
+        // Note: We don't need a pqueue, since len should always be one
+        let mut seen = HashMap::new();
+        seen.insert(&valve.name, 0);
+        let mut todo = VecDeque::new();
+        todo.push_back(&valve.name);
+
+        while let Some(name) = todo.pop_front() {
+            let v = &self.all[name];
+            let dist = seen[name];
+            for t in &v.tunnels {
+                assert!(t.len == 1);
+                if !seen.contains_key(&t.target) {
+                    seen.insert(&t.target, dist + t.len);
+                    todo.push_back(&t.target);
+                }
+            }
+        }
+
+        let tunnels = seen
+            .iter()
+            .filter(|(k, _)| self.all[**k].rate != 0)
+            .map(|(k, d)| Tunnel {
+                target: (*k).to_owned(),
+                len: *d,
+            })
+            .collect();
+        Some(Valve {
+            name: valve.name.to_owned(),
+            rate: valve.rate,
+            tunnels,
+        })
+    }
+
+    fn simplify(&self) -> Valves {
+        self.all
+            .values()
+            .filter_map(|v| self.simplified_valve(v))
+            .map(|v| (v.name.to_owned(), v))
+            .collect()
+    }
+
+    fn valid_moves(&self, state: &State) -> Vec<Move> {
+        let mut ret = Vec::new();
+        let max_left = state.positions.iter().map(|p| p.turns_left).min().unwrap();
+
+        for idx in 0..state.positions.len() {
+            let pos = &state.positions[idx];
+            // Only the start can be missing, if the input has no valve by its name.
+            let Some(vstart) = self.valves.get(&pos.valve) else {
+                continue;
+            };
+            for tun in &vstart.tunnels {
+                let turns_left = pos.turns_left - tun.len - 1;
+                if turns_left > 0 && turns_left <= max_left && !state.opened.contains(&tun.target) {
+                    ret.push(Move {
+                        idx,
+                        pos: Opener {
+                            turns_left,
+                            valve: tun.target.clone(),
+                        },
+                    })
+                }
+            }
+        }
+
+        ret
+    }
+
+    fn next_state(&self, state: &State, mv: Move) -> State {
+        let target = &mv.pos.valve;
+        let score = state.score + mv.pos.turns_left * self.valves[target].rate;
+
+        let mut opened = state.opened.clone();
+        opened.insert(target.clone());
+
+        let mut positions = state.positions.clone();
+        positions[mv.idx] = mv.pos;
+
+        State {
+            opened,
+            positions,
+            score,
+        }
+    }
+
+    fn solve(&self, state: &State) -> i32 {
+        let mut best = state.score;
+        for mv in self.valid_moves(state) {
+            let next = self.next_state(state, mv);
+            best = best.max(self.solve(&next));
+        }
+        best
+    }
+
+    /// Whether there are few enough valves worth opening for `best_by_opened` to have an entry
+    /// for every set of them.
+    fn few_valves(&self) -> bool {
+        self.valves.values().filter(|v| v.rate != 0).count() <= MAX_BITMASK_VALVES
+    }
+
+    /// The most pressure one opener can release in `minutes` for each set of opened valves,
+    /// indexed by a bitmask over the valves worth opening.
+    fn best_by_opened(&self, minutes: i32) -> Vec<i32> {
+        let bits: HashMap<&str, usize> = self
+            .valves
+            .values()
+            .filter(|v| v.rate != 0)
+            .enumerate()
+            .map(|(i, v)| (v.name.as_str(), 1 << i))
+            .collect();
+        let mut best = vec![0; 1 << bits.len()];
+        self.visit(&bits, &self.start, minutes, 0, 0, &mut best);
+        best
+    }
+
+    fn visit(
+        &self,
+        bits: &HashMap<&str, usize>,
+        valve: &str,
+        turns_left: i32,
+        opened: usize,
+        score: i32,
+        best: &mut [i32],
+    ) {
+        best[opened] = best[opened].max(score);
+        let Some(valve) = self.valves.get(valve) else {
+            return;
+        };
+        for tun in &valve.tunnels {
+            let bit = bits[tun.target.as_str()];
+            let turns_left = turns_left - tun.len - 1;
+            if turns_left > 0 && opened & bit == 0 {
+                let score = score + turns_left * self.valves[&tun.target].rate;
+                self.visit(bits, &tun.target, turns_left, opened | bit, score, best);
+            }
+        }
+    }
+
+    fn part1_bitmask(&self) -> i32 {
+        self.best_by_opened(self.part1_minutes)
+            .into_iter()
+            .max()
+            .unwrap_or(0)
+    }
+
+    /// Part 2 as the best pair of openers that never open the same valve.
+    fn part2_bitmask(&self) -> i32 {
+        let mut best = self.best_by_opened(self.part2_minutes);
+        // Make each entry the best over all subsets of its valves.
+        for opened in 0..best.len() {
+            for bit in (0..usize::BITS).map(|i| 1 << i).filter(|b| opened & b != 0) {
+                best[opened] = best[opened].max(best[opened ^ bit]);
+            }
+        }
+        let all = best.len() - 1;
+        (0..best.len())
+            .map(|mine| best[mine] + best[all ^ mine])
+            .max()
+            .unwrap_or(0)
+    }
+
+    fn start_pos(&self, turns: i32) -> Opener {
+        Opener {
+            turns_left: turns,
+            valve: self.start.clone(),
+        }
+    }
+}
+
+impl Solution for Input {
+    type Part1 = i32;
+    type Part2 = i32;
+
+    fn parse(contents: &str) -> ParseResult<Self> {
+        let re =
+            Regex::new(r"Valve (..) has flow rate=(\d+); tunnel.? lead.? to valve.? (.*)").unwrap();
+
+        let mut valves = HashMap::new();
+        let mut targets = Vec::new();
+        for line in contents.lines() {
+            let caps = re
+                .captures(line)
+                .ok_or_else(|| ParseError::at(contents, line, "expected a valve"))?;
+            let name = &caps[1];
+            let tunnels: Vec<&str> = caps.get(3).unwrap().as_str().split(", ").collect();
+            targets.extend(tunnels.iter().copied());
+            let valve = Valve {
+                name: name.to_owned(),
+                rate: parse_part(contents, caps.get(2).unwrap().as_str())?,
+                tunnels: tunnels
+                    .iter()
+                    .map(|s| Tunnel {
+                        target: (*s).to_owned(),
+                        len: 1,
+                    })
+                    .collect(),
+            };
+            if valves.insert(name.to_owned(), valve).is_some() {
+                return Err(ParseError::at(contents, line, "duplicate valve"));
+            }
+        }
+
+        // Tunnels can only be checked once all the valves are known.
+        for target in targets {
+            if !valves.contains_key(target) {
+                return Err(ParseError::at(contents, target, "no such valve"));
+            }
+        }
+        // The start can be changed, so `configure` checks it's there. Without it the parts
+        // release no pressure.
+        let mut input = Input {
+            all: valves,
+            valves: HashMap::new(),
+            start: START.to_owned(),
+            part1_minutes: 30,
+            part2_minutes: 26,
+        };
+        input.valves = input.simplify();
+        Ok(input)
+    }
+
+    fn part1(&self) -> i32 {
+        let state = State {
+            opened: HashSet::new(),
+            positions: vec![self.start_pos(self.part1_minutes)],
+            score: 0,
+        };
+        self.solve(&state)
+    }
+
+    fn part2(&self) -> i32 {
+        let state = State {
+            opened: HashSet::new(),
+            positions: vec![
+                self.start_pos(self.part2_minutes),
+                self.start_pos(self.part2_minutes),
+            ],
+            score: 0,
+        };
+        self.solve(&state)
+    }
+
+    const PARAMS: &'static [&'static str] = &["start", "part1_minutes", "part2_minutes"];
+
+    fn configure(&mut self, params: &Params) -> Result<(), String> {
+        params.update("part1_minutes", &mut self.part1_minutes)?;
+        params.update("part2_minutes", &mut self.part2_minutes)?;
+        if let Some(start) = params.get::<String>("start")? {
+            if !self.all.contains_key(&start) {
+                return Err(format!("no valve {}", start));
+            }
+            self.start = start;
+            self.valves = self.simplify();
+        }
+        if !self.all.contains_key(&self.start) {
+            return Err(format!("no starting valve {}", self.start));
+        }
+        Ok(())
+    }
+
+    fn variants() -> Vec<Variant<Self>> {
+        vec![Variant {
+            name: "bitmask",
+            part1: Some(Input::part1_bitmask),
+            part2: Some(Input::part2_bitmask),
+            handles: Input::few_valves,
+        }]
+    }
+}
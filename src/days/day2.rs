@@ -0,0 +1,483 @@
+use std::fmt;
+use std::str::FromStr;
+
+use crate::{ParseError, ParseResult, Solution, Variant};
+
+/// How a round ends for us.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Outcome {
+    Lose,
+    Draw,
+    Win,
+}
+
+impl Outcome {
+    /// The outcome of playing shape `ours` against `theirs` in a cycle of `shapes` shapes.
+    /// Each shape beats the half of the others just before it in the cycle, so with rock,
+    /// paper and scissors in that order, paper beats rock.
+    pub fn of(ours: usize, theirs: usize, shapes: usize) -> Outcome {
+        match (ours + shapes - theirs) % shapes {
+            0 => Outcome::Draw,
+            d if d <= shapes / 2 => Outcome::Win,
+            _ => Outcome::Lose,
+        }
+    }
+}
+
+impl fmt::Display for Outcome {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(match self {
+            Outcome::Lose => "lose",
+            Outcome::Draw => "draw",
+            Outcome::Win => "win",
+        })
+    }
+}
+
+/// What a symbol in the second column tells us to do.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Move {
+    /// Play this shape.
+    Shape(usize),
+    /// Play whatever gets this outcome. With more than three shapes several can; aiming to win
+    /// or lose picks the one next to the opponent's in the cycle.
+    Outcome(Outcome),
+}
+
+/// A cyclic game like rock paper scissors, with any odd number of shapes, and how to read and
+/// score a strategy guide for it.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Rules {
+    pub shape_names: Vec<&'static str>,
+    /// Points for playing each shape, in cycle order.
+    pub shape_scores: Vec<i64>,
+    /// Points for losing, drawing and winning.
+    pub outcome_scores: [i64; 3],
+    /// The shape each symbol in the first column stands for.
+    pub theirs: Vec<(char, usize)>,
+    /// The move each symbol in the second column stands for.
+    pub ours: Vec<(char, Move)>,
+}
+
+impl Rules {
+    /// Rock, paper and scissors, reading the second column as what to play.
+    pub fn part1() -> Self {
+        Rules {
+            shape_names: vec!["rock", "paper", "scissors"],
+            shape_scores: vec![1, 2, 3],
+            outcome_scores: [0, 3, 6],
+            theirs: vec![('A', 0), ('B', 1), ('C', 2)],
+            ours: vec![
+                ('X', Move::Shape(0)),
+                ('Y', Move::Shape(1)),
+                ('Z', Move::Shape(2)),
+            ],
+        }
+    }
+
+    /// Rock, paper and scissors, reading the second column as how the round should end.
+    pub fn part2() -> Self {
+        Rules {
+            ours: vec![
+                ('X', Move::Outcome(Outcome::Lose)),
+                ('Y', Move::Outcome(Outcome::Draw)),
+                ('Z', Move::Outcome(Outcome::Win)),
+            ],
+            ..Rules::part1()
+        }
+    }
+
+    /// Rock, paper, scissors, lizard, Spock, with symbols `A` to `E` and `V` to `Z` for the
+    /// shapes in that order, scoring 1 to 5.
+    pub fn rpsls() -> Self {
+        // Where each shape is in the cycle rock, Spock, paper, lizard, scissors, in which each
+        // beats the two before it: paper covers rock and disproves Spock, lizard poisons Spock
+        // and eats paper, and so on.
+        let cycle = [0, 2, 4, 3, 1];
+        let shape = |n: usize| cycle[n];
+        let names = ["rock", "paper", "scissors", "lizard", "Spock"];
+        let (mut shape_names, mut shape_scores) = (vec![""; 5], vec![0; 5]);
+        for (n, &at) in cycle.iter().enumerate() {
+            shape_names[at] = names[n];
+            shape_scores[at] = n as i64 + 1;
+        }
+        Rules {
+            shape_names,
+            shape_scores,
+            outcome_scores: [0, 3, 6],
+            theirs: "ABCDE"
+                .chars()
+                .enumerate()
+                .map(|(n, c)| (c, shape(n)))
+                .collect(),
+            ours: "VWXYZ"
+                .chars()
+                .enumerate()
+                .map(|(n, c)| (c, Move::Shape(shape(n))))
+                .collect(),
+        }
+    }
+
+    pub fn shapes(&self) -> usize {
+        self.shape_scores.len()
+    }
+
+    /// Check the rules make a fair game: an odd number of shapes, and symbols for real shapes.
+    /// Scoring checks first, and scores nothing under rules that fail.
+    pub fn check(&self) -> Result<(), String> {
+        let shapes = self.shapes();
+        if shapes.is_multiple_of(2) {
+            return Err(format!("need an odd number of shapes, not {}", shapes));
+        }
+        if self.shape_names.len() != shapes {
+            return Err(format!("need {} shape names", shapes));
+        }
+        let used = self
+            .theirs
+            .iter()
+            .copied()
+            .chain(self.ours.iter().filter_map(|&(c, m)| match m {
+                Move::Shape(s) => Some((c, s)),
+                Move::Outcome(_) => None,
+            }));
+        for (symbol, shape) in used {
+            if shape >= shapes {
+                return Err(format!(
+                    "{} stands for shape {} of {}",
+                    symbol, shape, shapes
+                ));
+            }
+        }
+        Ok(())
+    }
+
+    /// The shape we play against `theirs` when told `ours`, if both are real shapes.
+    pub fn play(&self, theirs: usize, ours: Move) -> Option<usize> {
+        let shapes = self.shapes();
+        if theirs >= shapes {
+            return None;
+        }
+        let ours = match ours {
+            Move::Shape(s) => s,
+            Move::Outcome(Outcome::Draw) => theirs,
+            Move::Outcome(Outcome::Win) => (theirs + 1) % shapes,
+            Move::Outcome(Outcome::Lose) => (theirs + shapes - 1) % shapes,
+        };
+        (ours < shapes).then_some(ours)
+    }
+
+    /// What playing `ours` against `theirs` scores; both must be real shapes.
+    fn points(&self, ours: usize, theirs: usize) -> i64 {
+        let outcome = Outcome::of(ours, theirs, self.shapes());
+        self.shape_scores[ours] + self.outcome_scores[outcome as usize]
+    }
+
+    /// Our score for a round, if the rules pass `check` and have both its symbols.
+    pub fn score(&self, round: Round) -> Option<i64> {
+        self.check().ok()?;
+        self.score_checked(round)
+    }
+
+    fn score_checked(&self, round: Round) -> Option<i64> {
+        let theirs = self.theirs.get(round.theirs)?.1;
+        let ours = self.play(theirs, self.ours.get(round.ours)?.1)?;
+        Some(self.points(ours, theirs))
+    }
+
+    /// Our score over every round, if the rules pass `check` and have all their symbols.
+    pub fn total(&self, rounds: &[Round]) -> Option<i64> {
+        self.check().ok()?;
+        rounds.iter().map(|&r| self.score_checked(r)).sum()
+    }
+
+    /// The best and worst totals we could get against the opponent's moves in `rounds`,
+    /// whatever the guide says, if the rules pass `check` and have all their symbols.
+    pub fn range(&self, rounds: &[Round]) -> Option<(i64, i64)> {
+        self.check().ok()?;
+        let (mut best, mut worst) = (0, 0);
+        for round in rounds {
+            let theirs = self.theirs.get(round.theirs)?.1;
+            let scores = (0..self.shapes()).map(|ours| self.points(ours, theirs));
+            best += scores.clone().max()?;
+            worst += scores.min()?;
+        }
+        Some((best, worst))
+    }
+
+    /// What `m` means, like `rock` or `win`.
+    pub fn describe(&self, m: Move) -> String {
+        match m {
+            Move::Shape(s) => match self.shape_names.get(s) {
+                Some(name) => name.to_string(),
+                None => format!("shape {}", s),
+            },
+            Move::Outcome(o) => o.to_string(),
+        }
+    }
+
+    /// Read one line of a strategy guide, with errors placed within `line`.
+    pub fn parse_round(&self, line: &str) -> ParseResult<Round> {
+        let parts: Vec<&str> = line.split_whitespace().collect();
+        if parts.len() != 2 {
+            return Err(ParseError::at_offset(line, 0, "expected two columns"));
+        }
+        let position = |symbols: &[char], part: &str| {
+            let mut chars = part.chars();
+            match (chars.next(), chars.next()) {
+                (Some(c), None) => symbols.iter().position(|&s| s == c),
+                _ => None,
+            }
+            .ok_or_else(|| ParseError::at(line, part, expected(symbols)))
+        };
+        let theirs: Vec<char> = self.theirs.iter().map(|&(c, _)| c).collect();
+        let ours: Vec<char> = self.ours.iter().map(|&(c, _)| c).collect();
+        Ok(Round {
+            theirs: position(&theirs, parts[0])?,
+            ours: position(&ours, parts[1])?,
+        })
+    }
+
+    /// Read a strategy guide, a round per line.
+    pub fn parse(&self, input: &str) -> ParseResult<Vec<Round>> {
+        input
+            .lines()
+            .enumerate()
+            .map(|(i, l)| self.parse_round(l).map_err(|e| e.on_line(i + 1)))
+            .collect()
+    }
+
+    /// These rules with the second column's meanings shuffled among its symbols in every
+    /// possible way, starting with the rules as they are.
+    pub fn readings(&self) -> Vec<Rules> {
+        let (symbols, moves): (Vec<char>, Vec<Move>) = self.ours.iter().copied().unzip();
+        permutations(&moves)
+            .into_iter()
+            .map(|moves| Rules {
+                ours: symbols.iter().copied().zip(moves).collect(),
+                ..self.clone()
+            })
+            .collect()
+    }
+}
+
+/// Every ordering of `items`, starting with the one given.
+fn permutations<T: Clone>(items: &[T]) -> Vec<Vec<T>> {
+    if items.is_empty() {
+        return vec![Vec::new()];
+    }
+    let mut all = Vec::new();
+    for i in 0..items.len() {
+        let mut rest = items.to_vec();
+        let first = rest.remove(i);
+        for mut tail in permutations(&rest) {
+            tail.insert(0, first.clone());
+            all.push(tail);
+        }
+    }
+    all
+}
+
+/// How a strategy guide scores under every reading of its second column, to work out what
+/// it was meant to say.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Analysis {
+    /// The most and least we could score against the opponent's moves, playing anything.
+    pub best: i64,
+    pub worst: i64,
+    /// Each reading, named for the rules it came from, with its total.
+    pub readings: Vec<(&'static str, Rules, i64)>,
+}
+
+impl Analysis {
+    /// Score `rounds` under every reading of each of `rules`, which should share the first
+    /// column and scores.
+    pub fn new(rounds: &[Round], rules: &[(&'static str, Rules)]) -> Option<Self> {
+        let (best, worst) = rules.first()?.1.range(rounds)?;
+        let mut readings = Vec::new();
+        for (name, r) in rules {
+            for reading in r.readings() {
+                let total = reading.total(rounds)?;
+                readings.push((*name, reading, total));
+            }
+        }
+        Some(Analysis {
+            best,
+            worst,
+            readings,
+        })
+    }
+
+    /// The reading the guide most likely meant. It was written to help us win, so that's
+    /// whichever scores highest, with ties going to the earlier.
+    pub fn likely(&self) -> &(&'static str, Rules, i64) {
+        let best = self.readings.iter().map(|r| r.2).max().unwrap_or(0);
+        self.readings.iter().find(|r| r.2 == best).unwrap()
+    }
+}
+
+/// What a column of the guide can hold, like `expected A, B or C`.
+fn expected(symbols: &[char]) -> String {
+    let names: Vec<String> = symbols.iter().map(|c| c.to_string()).collect();
+    match names.split_last() {
+        None => "expected nothing".to_owned(),
+        Some((last, [])) => format!("expected {}", last),
+        Some((last, rest)) => format!("expected {} or {}", rest.join(", "), last),
+    }
+}
+
+/// One line of the strategy guide, as where its symbols are in the rules' `theirs` and
+/// `ours`. Readings of the same rules share symbols, so a round means something under each.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Round {
+    pub theirs: usize,
+    pub ours: usize,
+}
+
+/// A shape in plain rock paper scissors, in cycle order, so each beats the one before it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Shape {
+    Rock,
+    Paper,
+    Scissors,
+}
+
+impl Shape {
+    const ALL: [Shape; 3] = [Shape::Rock, Shape::Paper, Shape::Scissors];
+
+    pub fn score(self) -> i64 {
+        self as i64 + 1
+    }
+
+    /// How playing `self` against `other` ends.
+    pub fn against(self, other: Shape) -> Outcome {
+        Outcome::of(self as usize, other as usize, 3)
+    }
+
+    /// The shape to play against `self` to get `outcome`.
+    pub fn for_outcome(self, outcome: Outcome) -> Shape {
+        Shape::ALL
+            .into_iter()
+            .find(|s| s.against(self) == outcome)
+            .unwrap()
+    }
+}
+
+impl Outcome {
+    pub fn score(self) -> i64 {
+        self as i64 * 3
+    }
+}
+
+/// The second column of a guide, which part 1 reads as a shape and part 2 as an outcome.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Response {
+    X,
+    Y,
+    Z,
+}
+
+impl Response {
+    const ALL: [Response; 3] = [Response::X, Response::Y, Response::Z];
+
+    pub fn shape(self) -> Shape {
+        Shape::ALL[self as usize]
+    }
+
+    pub fn outcome(self) -> Outcome {
+        [Outcome::Lose, Outcome::Draw, Outcome::Win][self as usize]
+    }
+}
+
+/// One line of the guide for plain rock paper scissors, like `A Y`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Input {
+    pub other: Shape,
+    pub me: Response,
+}
+
+impl FromStr for Input {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> ParseResult<Self> {
+        Rules::part1().parse_round(s).map(Input::from_round)
+    }
+}
+
+impl Input {
+    /// A round read with `Rules::part1`, which has three symbols a column.
+    fn from_round(round: Round) -> Self {
+        Input {
+            other: Shape::ALL[round.theirs],
+            me: Response::ALL[round.ours],
+        }
+    }
+
+    /// The score when the second column says what to play.
+    pub fn score1(&self) -> i64 {
+        let me = self.me.shape();
+        me.score() + me.against(self.other).score()
+    }
+
+    /// The score when the second column says how the round should end.
+    pub fn score2(&self) -> i64 {
+        let outcome = self.me.outcome();
+        self.other.for_outcome(outcome).score() + outcome.score()
+    }
+
+    /// The line for scoring with `Rules::part1` or `Rules::part2`.
+    pub fn round(&self) -> Round {
+        Round {
+            theirs: self.other as usize,
+            ours: self.me as usize,
+        }
+    }
+}
+
+pub struct Inputs(Vec<Input>);
+
+impl Inputs {
+    pub fn rounds(&self) -> Vec<Round> {
+        self.0.iter().map(Input::round).collect()
+    }
+
+    /// How the guide scores under every reading of part 1's and part 2's rules.
+    pub fn analyze(&self) -> Analysis {
+        let rules = [("part1", Rules::part1()), ("part2", Rules::part2())];
+        Analysis::new(&self.rounds(), &rules).unwrap()
+    }
+
+    fn part1_typed(&self) -> i64 {
+        self.0.iter().map(Input::score1).sum()
+    }
+
+    fn part2_typed(&self) -> i64 {
+        self.0.iter().map(Input::score2).sum()
+    }
+}
+
+impl Solution for Inputs {
+    type Part1 = i64;
+    type Part2 = i64;
+
+    fn parse(input: &str) -> ParseResult<Self> {
+        let rounds = Rules::part1().parse(input)?;
+        Ok(Inputs(rounds.into_iter().map(Input::from_round).collect()))
+    }
+
+    fn part1(&self) -> i64 {
+        Rules::part1().total(&self.rounds()).unwrap()
+    }
+
+    fn part2(&self) -> i64 {
+        Rules::part2().total(&self.rounds()).unwrap()
+    }
+
+    fn variants() -> Vec<Variant<Self>> {
+        vec![Variant {
+            name: "typed",
+            part1: Some(Inputs::part1_typed),
+            part2: Some(Inputs::part2_typed),
+            handles: |_| true,
+        }]
+    }
+}
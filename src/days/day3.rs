@@ -0,0 +1,127 @@
+use std::collections::{HashMap, HashSet};
+use std::str::FromStr;
+
+use crate::{ParseError, ParseResult, Solution};
+
+#[derive(Debug, Clone)]
+struct Input {
+    a: Vec<char>,
+    b: Vec<char>,
+}
+
+impl FromStr for Input {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> ParseResult<Self> {
+        if let Some(i) = s.find(|c: char| !c.is_ascii_alphabetic()) {
+            return Err(ParseError::at_offset(s, i, "items must be letters"));
+        }
+        if !s.len().is_multiple_of(2) {
+            return Err(ParseError::at_end(s, "compartments must be the same size"));
+        }
+        let chars: Vec<char> = s.chars().collect();
+        let (a, b) = chars.split_at(chars.len() / 2);
+        let input = Input {
+            a: a.to_owned(),
+            b: b.to_owned(),
+        };
+        if input.shared().is_none() {
+            return Err(ParseError::at_offset(s, 0, "compartments share no item"));
+        }
+        Ok(input)
+    }
+}
+
+impl Input {
+    fn priority(c: char) -> u32 {
+        if c.is_lowercase() {
+            c.to_digit(36).unwrap() - 10 + 1
+        } else {
+            c.to_digit(36).unwrap() - 10 + 26 + 1
+        }
+    }
+
+    fn shared(&self) -> Option<char> {
+        let aset = self.a.iter().collect::<HashSet<_>>();
+        let bset = self.b.iter().collect::<HashSet<_>>();
+        aset.intersection(&bset).next().map(|c| **c)
+    }
+
+    /// The item in both compartments, which parsing checks there is.
+    fn common(&self) -> char {
+        self.shared().unwrap()
+    }
+
+    fn all(&self) -> HashSet<&char> {
+        self.a.iter().chain(self.b.iter()).collect::<HashSet<_>>()
+    }
+}
+
+pub struct Inputs(Vec<Input>);
+
+impl Inputs {
+    fn groups(&self) -> Vec<Group> {
+        self.0.chunks(3).map(|g| Group(g.to_vec())).collect()
+    }
+}
+
+impl Solution for Inputs {
+    type Part1 = u32;
+    type Part2 = u32;
+
+    fn parse(input: &str) -> ParseResult<Self> {
+        let parsed: Vec<Input> = input
+            .lines()
+            .enumerate()
+            .map(|(i, l)| l.parse::<Input>().map_err(|e| e.on_line(i + 1)))
+            .collect::<ParseResult<Vec<Input>>>()?;
+        let inputs = Inputs(parsed);
+        if !inputs.0.len().is_multiple_of(3) {
+            return Err(ParseError::at_end(
+                input,
+                "elves must come in groups of three",
+            ));
+        }
+        for (i, group) in inputs.groups().iter().enumerate() {
+            if group.shared().is_none() {
+                let line = input.lines().nth(i * 3).unwrap();
+                return Err(ParseError::at_offset(line, 0, "group has no badge").on_line(i * 3 + 1));
+            }
+        }
+        Ok(inputs)
+    }
+
+    fn part1(&self) -> u32 {
+        self.0
+            .iter()
+            .map(|i| Input::priority(i.common()))
+            .sum::<u32>()
+    }
+
+    fn part2(&self) -> u32 {
+        self.groups()
+            .iter()
+            .map(|g| Input::priority(g.badge()))
+            .sum()
+    }
+}
+
+struct Group(Vec<Input>);
+
+impl Group {
+    fn shared(&self) -> Option<char> {
+        let mut counts = HashMap::new();
+        for input in &self.0 {
+            for c in input.all() {
+                *counts.entry(*c).or_insert(0) += 1;
+            }
+        }
+
+        counts.iter().find(|e| *e.1 == 3).map(|e| *e.0)
+    }
+
+    /// The item all three elves carry, which parsing checks there is.
+    fn badge(&self) -> char {
+        self.shared().unwrap()
+    }
+}
@@ -0,0 +1,85 @@
+use std::str::FromStr;
+
+use crate::error::parse_part;
+use crate::{ParseError, ParseResult, Solution};
+
+#[derive(Debug, Clone, Copy)]
+struct Range {
+    start: i32,
+    end: i32,
+}
+
+impl Range {
+    /// Parse `s`, a part of `line`.
+    fn parse(line: &str, s: &str) -> ParseResult<Self> {
+        let (start, end) = s
+            .split_once('-')
+            .ok_or_else(|| ParseError::at(line, s, "expected a range like 2-4"))?;
+        Ok(Range {
+            start: parse_part(line, start)?,
+            end: parse_part(line, end)?,
+        })
+    }
+
+    fn contains(&self, other: &Range) -> bool {
+        self.start <= other.start && self.end >= other.end
+    }
+
+    fn overlaps(&self, other: &Range) -> bool {
+        self.start <= other.end && self.end >= other.start
+    }
+}
+
+#[derive(Debug)]
+struct Pair {
+    a: Range,
+    b: Range,
+}
+
+impl FromStr for Pair {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> ParseResult<Self> {
+        let (a, b) = s
+            .split_once(',')
+            .ok_or_else(|| ParseError::at_end(s, "expected two ranges separated by ','"))?;
+        Ok(Self {
+            a: Range::parse(s, a)?,
+            b: Range::parse(s, b)?,
+        })
+    }
+}
+
+impl Pair {
+    fn has_fully_contained(&self) -> bool {
+        self.a.contains(&self.b) || self.b.contains(&self.a)
+    }
+    fn has_overlap(&self) -> bool {
+        self.a.overlaps(&self.b)
+    }
+}
+
+#[derive(Debug)]
+pub struct Inputs(Vec<Pair>);
+
+impl Solution for Inputs {
+    type Part1 = u32;
+    type Part2 = u32;
+
+    fn parse(input: &str) -> ParseResult<Self> {
+        let parsed = input
+            .lines()
+            .enumerate()
+            .map(|(i, l)| l.parse::<Pair>().map_err(|e| e.on_line(i + 1)))
+            .collect::<ParseResult<Vec<_>>>()?;
+        Ok(Inputs(parsed))
+    }
+
+    fn part1(&self) -> u32 {
+        self.0.iter().filter(|p| p.has_fully_contained()).count() as u32
+    }
+
+    fn part2(&self) -> u32 {
+        self.0.iter().filter(|p| p.has_overlap()).count() as u32
+    }
+}
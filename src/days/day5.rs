@@ -0,0 +1,205 @@
+use regex::Regex;
+use std::fmt;
+use MoveMode::{InOrder, Reverse};
+
+use crate::error::parse_part;
+use crate::vis::{Frame, Hidden};
+use crate::{ParseError, ParseResult, Solution, Viewer};
+
+#[derive(PartialEq)]
+enum MoveMode {
+    Reverse,
+    InOrder,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+struct Stack(Vec<char>);
+
+impl Stack {
+    fn remove(&mut self, s: usize) -> Vec<char> {
+        let at = self.0.len() - s;
+        self.0.split_off(at)
+    }
+
+    fn add(&mut self, v: &mut Vec<char>) {
+        self.0.append(v)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+struct Stacks(Vec<Stack>);
+
+impl Stacks {
+    fn stack(&mut self, one_idx: usize) -> &mut Stack {
+        self.0.get_mut(one_idx - 1).expect("no such stack")
+    }
+
+    fn do_move(&mut self, mv: &Move, mode: &MoveMode) {
+        let mut crates = self.stack(mv.source).remove(mv.count);
+        if *mode == Reverse {
+            crates.reverse();
+        }
+        self.stack(mv.dest).add(&mut crates);
+    }
+
+    fn tops(&self) -> String {
+        let tops = self
+            .0
+            .iter()
+            .filter_map(|s| s.0.last())
+            .map(|c| c.to_string())
+            .collect::<Vec<_>>();
+        tops.join("")
+    }
+}
+
+#[derive(Debug, PartialEq)]
+struct Move {
+    count: usize,
+    // one-based indexes
+    source: usize,
+    dest: usize,
+}
+
+#[derive(Debug, PartialEq)]
+pub struct Input {
+    stacks: Stacks,
+    moves: Vec<Move>,
+}
+
+/// Draws the stacks as they appear in the puzzle input, without trailing spaces.
+impl fmt::Display for Stacks {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let tallest = self.0.iter().map(|s| s.0.len()).max().unwrap_or(0);
+        for level in (0..tallest).rev() {
+            let row = self
+                .0
+                .iter()
+                .map(|s| match s.0.get(level) {
+                    Some(c) => format!("[{}]", c),
+                    None => "   ".to_owned(),
+                })
+                .collect::<Vec<_>>();
+            writeln!(f, "{}", row.join(" ").trim_end())?;
+        }
+        let labels = (1..=self.0.len())
+            .map(|i| format!(" {} ", i))
+            .collect::<Vec<_>>();
+        writeln!(f, "{}", labels.join(" ").trim_end())
+    }
+}
+
+impl fmt::Display for Move {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "move {} from {} to {}",
+            self.count, self.source, self.dest
+        )
+    }
+}
+
+impl fmt::Display for Input {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        writeln!(f, "{}", self.stacks)?;
+        for m in &self.moves {
+            writeln!(f, "{}", m)?;
+        }
+        Ok(())
+    }
+}
+
+impl Input {
+    fn execute(&self, mode: &MoveMode, viewer: &mut dyn Viewer) -> Stacks {
+        let mut stacks = self.stacks.clone();
+        for m in &self.moves {
+            stacks.do_move(m, mode);
+            viewer.show(&mut || Frame::new(m.to_string()).text(&stacks.to_string()));
+        }
+        stacks
+    }
+}
+
+impl Solution for Input {
+    type Part1 = String;
+    type Part2 = String;
+
+    fn parse(contents: &str) -> ParseResult<Input> {
+        let mut input = Input {
+            stacks: Stacks(Vec::new()),
+            moves: Vec::new(),
+        };
+
+        let (stack_part, move_part) = contents.split_once("\n\n").ok_or_else(|| {
+            ParseError::at_end(contents, "expected a blank line after the stacks")
+        })?;
+        let mut rev = stack_part.lines().rev();
+
+        let header = rev
+            .next()
+            .ok_or_else(|| ParseError::at_offset(contents, 0, "expected stacks"))?;
+        let stack_count = header.split_whitespace().count();
+        input
+            .stacks
+            .0
+            .resize_with(stack_count, || Stack(Vec::new()));
+
+        for line in rev {
+            let chars = line.chars().collect::<Vec<_>>();
+            for (i, st) in input.stacks.0.iter_mut().enumerate() {
+                if let Some(chr) = chars.get(1 + i * 4) {
+                    if chr.is_alphabetic() {
+                        st.0.push(*chr);
+                    }
+                }
+            }
+        }
+
+        // Track stack heights, so moves that can't be done are caught here.
+        let mut heights: Vec<usize> = input.stacks.0.iter().map(|s| s.0.len()).collect();
+        let move_re = Regex::new(r"\Amove (\d+) from (\d+) to (\d+)\z").expect("regex compile");
+        for line in move_part.lines().filter(|l| !l.is_empty()) {
+            let caps = move_re
+                .captures(line)
+                .ok_or_else(|| ParseError::at(contents, line, "expected \"move N from A to B\""))?;
+            let field = |i| caps.get(i).unwrap().as_str();
+            let mv = Move {
+                count: parse_part(contents, field(1))?,
+                source: parse_part(contents, field(2))?,
+                dest: parse_part(contents, field(3))?,
+            };
+            for (i, idx) in [(2, mv.source), (3, mv.dest)] {
+                if idx == 0 || idx > stack_count {
+                    let msg = format!("no stack {}, expected 1 to {}", idx, stack_count);
+                    return Err(ParseError::at(contents, field(i), msg));
+                }
+            }
+            if mv.count > heights[mv.source - 1] {
+                let msg = format!(
+                    "only {} crates on stack {}",
+                    heights[mv.source - 1],
+                    mv.source
+                );
+                return Err(ParseError::at(contents, field(1), msg));
+            }
+            heights[mv.source - 1] -= mv.count;
+            heights[mv.dest - 1] += mv.count;
+            input.moves.push(mv);
+        }
+
+        Ok(input)
+    }
+
+    fn part1(&self) -> String {
+        self.execute(&Reverse, &mut Hidden).tops()
+    }
+
+    fn part2(&self) -> String {
+        self.execute(&InOrder, &mut Hidden).tops()
+    }
+
+    fn visualize(&self, part: u32, viewer: &mut dyn Viewer) -> Option<String> {
+        let mode = if part == 1 { Reverse } else { InOrder };
+        Some(self.execute(&mode, viewer).tops())
+    }
+}
@@ -0,0 +1,106 @@
+use std::collections::{HashMap, VecDeque};
+
+use crate::{Params, ParseResult, Solution};
+
+struct Stats {
+    target: usize,
+    total: usize,
+    deq: VecDeque<char>,
+    counts: HashMap<char, usize>,
+}
+
+impl Stats {
+    fn new(target: usize) -> Self {
+        Stats {
+            target,
+            total: 0,
+            deq: VecDeque::new(),
+            counts: HashMap::new(),
+        }
+    }
+
+    fn complete(&self) -> bool {
+        self.total >= self.target && self.counts.iter().all(|(_, v)| *v <= 1)
+    }
+
+    fn add(&mut self, c: char) {
+        self.total += 1;
+        self.deq.push_back(c);
+        *self.counts.entry(c).or_insert(0) += 1;
+        if self.deq.len() > self.target {
+            let d = self.deq.pop_front().unwrap();
+            *self.counts.entry(d).or_insert(0) -= 1
+        }
+    }
+
+    fn find(s: &str, target: usize) -> Option<usize> {
+        let mut stats = Stats::new(target);
+        for c in s.chars() {
+            stats.add(c);
+            if stats.complete() {
+                return Some(stats.total);
+            }
+        }
+        None
+    }
+
+    /// Where the marker ends, or 0 if there isn't one, as every marker ends past the first
+    /// character. `configure` rejects windows without one, but a bare `parse` doesn't know
+    /// what windows will be asked for.
+    fn solve(s: &str, target: usize) -> usize {
+        Stats::find(s, target).unwrap_or(0)
+    }
+}
+
+/// Check `data` has markers of both sizes.
+fn check(data: &str, windows: [usize; 2]) -> Result<(), String> {
+    for window in windows {
+        if window == 0 {
+            return Err("a marker needs at least one character".to_owned());
+        }
+        if Stats::find(data, window).is_none() {
+            return Err(format!(
+                "no marker of {} different characters found",
+                window
+            ));
+        }
+    }
+    Ok(())
+}
+
+pub struct Signal {
+    data: String,
+    /// How many different characters in a row make a marker.
+    part1_window: usize,
+    part2_window: usize,
+}
+
+impl Solution for Signal {
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> ParseResult<Self> {
+        // Whether there are markers depends on the windows, so `configure` checks for them.
+        Ok(Signal {
+            data: input.trim().to_owned(),
+            part1_window: 4,
+            part2_window: 14,
+        })
+    }
+
+    fn part1(&self) -> usize {
+        Stats::solve(&self.data, self.part1_window)
+    }
+
+    fn part2(&self) -> usize {
+        Stats::solve(&self.data, self.part2_window)
+    }
+
+    const PARAMS: &'static [&'static str] = &["part1_window", "part2_window"];
+
+    fn configure(&mut self, params: &Params) -> Result<(), String> {
+        params.update("part1_window", &mut self.part1_window)?;
+        params.update("part2_window", &mut self.part2_window)?;
+        check(&self.data, [self.part1_window, self.part2_window])
+    }
+}
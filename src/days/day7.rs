@@ -0,0 +1,205 @@
+use std::collections::{hash_map, HashMap};
+
+use crate::vis::Frame;
+use crate::{Answers, ParseError, ParseResult, Solution, Viewer};
+
+pub struct Dir {
+    children: HashMap<String, Node>,
+    size: u64,
+}
+
+impl Dir {
+    fn new() -> Dir {
+        Dir {
+            children: HashMap::new(),
+            size: 0,
+        }
+    }
+
+    fn add(&mut self, dir: &[String], name: &str, node: Node) -> Result<(), String> {
+        self.size = self
+            .size
+            .checked_add(node.size())
+            .ok_or("directory is too big")?;
+        if dir.is_empty() {
+            if self.children.contains_key(name) {
+                return Err(format!("node already exists at name {}", name));
+            }
+            self.children.insert(name.to_owned(), node);
+            Ok(())
+        } else {
+            match self.children.get_mut(&dir[0]) {
+                Some(Node::Dir(d)) => d.add(&dir[1..], name, node),
+                Some(_) => Err(format!("found a file, not a dir: {}", dir[0])),
+                _ => Err(format!("node {} unknown", dir[0])),
+            }
+        }
+    }
+
+    /// Draw the tree like the puzzle text does, with each directory's total size.
+    fn render(&self, name: &str, depth: usize, out: &mut Vec<String>) {
+        let indent = "  ".repeat(depth);
+        out.push(format!("{}- {} (dir, size={})", indent, name, self.size));
+        let mut children = self.children.iter().collect::<Vec<_>>();
+        children.sort_by_key(|(n, _)| *n);
+        for (n, c) in children {
+            match c {
+                Node::File(s) => out.push(format!("{}  - {} (file, size={})", indent, n, s)),
+                Node::Dir(d) => d.render(n, depth + 1, out),
+            }
+        }
+    }
+
+    fn frame(&self, caption: &str) -> Frame {
+        let mut rows = Vec::new();
+        self.render("/", 0, &mut rows);
+        Frame {
+            caption: caption.to_owned(),
+            rows,
+        }
+    }
+
+    fn iter(&self) -> DirIterator<'_> {
+        let children = self.children.values();
+        DirIterator {
+            children,
+            child_iter: None,
+        }
+    }
+}
+
+impl Solution for Dir {
+    type Part1 = u64;
+    type Part2 = u64;
+
+    fn parse(input: &str) -> ParseResult<Self> {
+        parse(input)
+    }
+
+    fn part1(&self) -> u64 {
+        let mut sum: u64 = 0;
+        for node in self.iter() {
+            match node {
+                Node::Dir(d) if d.size <= 100_000 => sum += d.size,
+                _ => (),
+            }
+        }
+        sum
+    }
+
+    /// The smallest directory to delete to leave 30M of the 70M disk free, or 0 if there's
+    /// already room. The root always frees enough, so there's an answer either way.
+    fn part2(&self) -> u64 {
+        let target = self.size.saturating_sub(40_000_000);
+        if target == 0 {
+            return 0;
+        }
+        self.iter()
+            .filter_map(|n| match n {
+                Node::Dir(d) => Some(d.size),
+                _ => None,
+            })
+            .chain([self.size])
+            .filter(|s| *s >= target)
+            .min()
+            .unwrap()
+    }
+
+    /// Both parts only look at the finished tree, so there's just the one frame.
+    fn visualize(&self, part: u32, viewer: &mut dyn Viewer) -> Option<String> {
+        viewer.show(&mut || self.frame("filesystem"));
+        Some(Answers::part(self, part))
+    }
+}
+
+struct DirIterator<'a> {
+    children: hash_map::Values<'a, String, Node>,
+    child_iter: Option<Box<DirIterator<'a>>>,
+}
+
+impl<'a> Iterator for DirIterator<'a> {
+    type Item = &'a Node;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if let Some(r) = self.child_iter.as_mut().and_then(|i| i.next()) {
+            Some(r)
+        } else {
+            let i = self.children.next();
+            match i {
+                Some(Node::Dir(d)) => self.child_iter = Some(Box::new(d.iter())),
+                _ => self.child_iter = None,
+            }
+            i
+        }
+    }
+}
+
+enum Node {
+    File(u64),
+    Dir(Dir),
+}
+
+impl Node {
+    fn size(&self) -> u64 {
+        match self {
+            Node::File(s) => *s,
+            _ => 0,
+        }
+    }
+}
+
+#[derive(Debug)]
+enum Input {
+    Cd(String),
+    Ls,
+    File(String, u64),
+    Dir(String),
+}
+
+fn parse_input(contents: &str, line: &str) -> ParseResult<Input> {
+    let parts: Vec<_> = line.split_whitespace().collect();
+    let size: Option<u64> = parts.first().and_then(|p| p.parse().ok());
+    if line == "$ ls" {
+        Ok(Input::Ls)
+    } else if parts.len() == 3 && parts[0] == "$" && parts[1] == "cd" {
+        Ok(Input::Cd(parts[2].to_owned()))
+    } else if parts.len() == 2 && parts[0] == "dir" {
+        Ok(Input::Dir(parts[1].to_owned()))
+    } else if let (2, Some(size)) = (parts.len(), size) {
+        Ok(Input::File(parts[1].to_owned(), size))
+    } else {
+        Err(ParseError::at(contents, line, "Unknown input"))
+    }
+}
+
+fn parse(contents: &str) -> ParseResult<Dir> {
+    let mut dir = Dir::new();
+    let mut path: Vec<String> = Vec::new();
+    let mut in_ls = false;
+    for line in contents.lines() {
+        let input = parse_input(contents, line)?;
+        let result = match (in_ls, &input) {
+            (true, Input::File(p, size)) => dir.add(path.as_slice(), p, Node::File(*size)),
+            (true, Input::Dir(p)) => dir.add(path.as_slice(), p, Node::Dir(Dir::new())),
+            (_, Input::Ls) => {
+                in_ls = true;
+                Ok(())
+            }
+            (_, Input::Cd(p)) => {
+                if p == "/" {
+                    path.clear();
+                } else if p == ".." {
+                    path.pop();
+                } else {
+                    path.push(p.to_owned());
+                }
+                in_ls = false;
+                Ok(())
+            }
+            _ => Err("listing outside of ls".to_owned()),
+        };
+        result.map_err(|msg| ParseError::at(contents, line, msg))?;
+    }
+
+    Ok(dir)
+}
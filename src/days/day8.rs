@@ -0,0 +1,77 @@
+use std::collections::HashSet;
+
+use crate::geometry::{Point, Vec2};
+use crate::grid;
+use crate::image::Rgb;
+use crate::{Image, ParseResult, Solution};
+
+/// Tree heights.
+pub struct Grid(grid::Grid<u8>);
+
+impl Grid {
+    /// Add the trees visible from outside the grid, looking along `line`.
+    fn out_visible(&self, seen: &mut HashSet<Point>, line: impl Iterator<Item = Point>) {
+        let mut highest = None;
+        for p in line {
+            let h = self.0[p];
+            if highest.is_none_or(|highest| h > highest) {
+                seen.insert(p);
+                highest = Some(h);
+            }
+        }
+    }
+
+    fn viewing_distance(&self, p: Point, step: Vec2) -> usize {
+        let h = self.0[p];
+        let mut seen = 0;
+        for o in self.0.ray(p, step) {
+            seen += 1;
+            if self.0[o] >= h {
+                break;
+            }
+        }
+        seen
+    }
+
+    fn score(&self, p: Point) -> usize {
+        Vec2::ORTHOGONAL
+            .iter()
+            .map(|&step| self.viewing_distance(p, step))
+            .product()
+    }
+}
+
+impl Solution for Grid {
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> ParseResult<Self> {
+        let grid = grid::Grid::parse(input, |_, c| {
+            c.to_digit(10).map(|d| d as u8).ok_or("expected a digit")
+        })?;
+        Ok(Grid(grid))
+    }
+
+    fn part1(&self) -> usize {
+        let mut seen = HashSet::new();
+        for y in 0..self.0.height() as i32 {
+            self.out_visible(&mut seen, self.0.row(y));
+            self.out_visible(&mut seen, self.0.row(y).rev());
+        }
+        for x in 0..self.0.width() as i32 {
+            self.out_visible(&mut seen, self.0.column(x));
+            self.out_visible(&mut seen, self.0.column(x).rev());
+        }
+        seen.len()
+    }
+
+    fn part2(&self) -> usize {
+        self.0.positions().map(|p| self.score(p)).max().unwrap()
+    }
+
+    fn pictures(&self) -> Vec<(&'static str, Image)> {
+        let (low, high) = (Rgb(20, 40, 20), Rgb(140, 230, 100));
+        let heights = Image::from_grid(&self.0, |_, &h| low.mix(high, h as f64 / 9.0));
+        vec![("heights", heights)]
+    }
+}
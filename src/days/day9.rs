@@ -0,0 +1,152 @@
+use std::collections::HashSet;
+
+use crate::error::parse_part;
+use crate::geometry::{Bounds, Dir, Point};
+use crate::image::Rgb;
+use crate::vis::{Frame, Hidden};
+use crate::{Image, Params, ParseError, ParseResult, Solution, Viewer};
+
+fn move_rope(rope: &mut [Point], dir: Dir) {
+    rope[0] += dir.offset();
+    for i in 1..rope.len() {
+        let head = rope[i - 1];
+        if rope[i].chebyshev_to(head) > 1 {
+            rope[i] = rope[i].step_towards(head);
+        }
+    }
+}
+
+struct Motion {
+    dir: Dir,
+    count: usize,
+}
+
+pub struct Motions {
+    motions: Vec<Motion>,
+    /// Knots in the rope, including the head.
+    part1_len: usize,
+    part2_len: usize,
+}
+
+/// The rope over the cells its tail has visited: `H` for the head, then each knot's number,
+/// `#` where the tail has been and `s` for the start.
+fn frame(caption: String, rope: &[Point], seen: &HashSet<Point>) -> Frame {
+    let bounds = Bounds::of(rope.iter().chain(seen).copied()).unwrap();
+    Frame::new(caption).area(bounds, |p| match rope.iter().position(|&k| k == p) {
+        Some(0) => 'H',
+        Some(i) => char::from_digit(i as u32 % 10, 10).unwrap(),
+        None if p == Point::ZERO => 's',
+        None if seen.contains(&p) => '#',
+        None => '.',
+    })
+}
+
+impl Motions {
+    fn solve(&self, len: usize, viewer: &mut dyn Viewer) -> usize {
+        self.visited(len, viewer).len()
+    }
+
+    /// Where the tail of a rope with `len` knots goes.
+    fn visited(&self, len: usize, viewer: &mut dyn Viewer) -> HashSet<Point> {
+        let mut seen: HashSet<Point> = HashSet::new();
+        let mut rope = vec![Point::ZERO; len];
+        seen.insert(*rope.last().unwrap());
+
+        for motion in &self.motions {
+            for step in 1..=motion.count {
+                move_rope(&mut rope, motion.dir);
+                seen.insert(*rope.last().unwrap());
+                let caption = || format!("{:?} {}/{}", motion.dir, step, motion.count);
+                viewer.show(&mut || frame(caption(), &rope, &seen));
+            }
+        }
+
+        seen
+    }
+
+    /// The tail's visits in white, with the start in red.
+    fn picture(&self, len: usize) -> Image {
+        let seen = self.visited(len, &mut Hidden);
+        let bounds = Bounds::of(seen.iter().copied()).unwrap();
+        let mut image = Image::new(
+            bounds.width() as usize,
+            bounds.height() as usize,
+            Rgb::BLACK,
+        );
+        for p in seen {
+            let colour = if p == Point::ZERO {
+                Rgb::RED
+            } else {
+                Rgb::WHITE
+            };
+            let p = p - bounds.min;
+            image.set(p.x as usize, p.y as usize, colour);
+        }
+        image
+    }
+}
+
+impl Solution for Motions {
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> ParseResult<Self> {
+        let mut motions = Vec::new();
+        for line in input.lines() {
+            let parts: Vec<_> = line.split_whitespace().collect();
+            if parts.len() != 2 {
+                return Err(ParseError::at(
+                    input,
+                    line,
+                    "expected a direction and a count",
+                ));
+            }
+            motions.push(Motion {
+                dir: parts[0]
+                    .parse()
+                    .map_err(|e: String| ParseError::at(input, parts[0], e))?,
+                count: parse_part(input, parts[1])?,
+            });
+        }
+        Ok(Motions {
+            motions,
+            part1_len: 2,
+            part2_len: 10,
+        })
+    }
+
+    fn part1(&self) -> usize {
+        self.solve(self.part1_len, &mut Hidden)
+    }
+
+    fn part2(&self) -> usize {
+        self.solve(self.part2_len, &mut Hidden)
+    }
+
+    fn visualize(&self, part: u32, viewer: &mut dyn Viewer) -> Option<String> {
+        let len = if part == 1 {
+            self.part1_len
+        } else {
+            self.part2_len
+        };
+        Some(self.solve(len, viewer).to_string())
+    }
+
+    fn pictures(&self) -> Vec<(&'static str, Image)> {
+        vec![
+            ("part1", self.picture(self.part1_len)),
+            ("part2", self.picture(self.part2_len)),
+        ]
+    }
+
+    const PARAMS: &'static [&'static str] = &["part1_len", "part2_len"];
+
+    fn configure(&mut self, params: &Params) -> Result<(), String> {
+        params.update("part1_len", &mut self.part1_len)?;
+        params.update("part2_len", &mut self.part2_len)?;
+        if self.part1_len == 0 || self.part2_len == 0 {
+            return Err("a rope needs at least one knot".to_owned());
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,87 @@
+use std::error;
+use std::fmt;
+use std::path::Path;
+use std::str::FromStr;
+
+/// A problem with puzzle input, and where it was found.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    pub file: Option<String>,
+    /// One-based position of the problem.
+    pub line: usize,
+    pub column: usize,
+    /// The whole line containing the problem.
+    pub text: String,
+    pub message: String,
+}
+
+pub type ParseResult<T> = Result<T, ParseError>;
+
+impl ParseError {
+    /// An error at byte `offset` into `input`.
+    pub fn at_offset(input: &str, offset: usize, message: impl Into<String>) -> Self {
+        let mut offset = offset.min(input.len());
+        while !input.is_char_boundary(offset) {
+            offset -= 1;
+        }
+        let before = &input[..offset];
+        let line_start = before.rfind('\n').map_or(0, |i| i + 1);
+        let line_end = input[offset..]
+            .find('\n')
+            .map_or(input.len(), |i| offset + i);
+        ParseError {
+            file: None,
+            line: before.matches('\n').count() + 1,
+            column: before[line_start..].chars().count() + 1,
+            text: input[line_start..line_end]
+                .trim_end_matches('\r')
+                .to_owned(),
+            message: message.into(),
+        }
+    }
+
+    /// An error at `part`, which must be a slice of `input`.
+    pub fn at(input: &str, part: &str, message: impl Into<String>) -> Self {
+        let offset = (part.as_ptr() as usize).saturating_sub(input.as_ptr() as usize);
+        ParseError::at_offset(input, offset, message)
+    }
+
+    /// An error just past the end of `input`.
+    pub fn at_end(input: &str, message: impl Into<String>) -> Self {
+        ParseError::at_offset(input, input.len(), message)
+    }
+
+    /// Move an error found by a single-line parser to its real line number.
+    pub fn on_line(self, line: usize) -> Self {
+        ParseError { line, ..self }
+    }
+
+    pub fn in_file(self, path: &Path) -> Self {
+        ParseError {
+            file: Some(path.display().to_string()),
+            ..self
+        }
+    }
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        if let Some(file) = &self.file {
+            write!(f, "{}:", file)?;
+        }
+        writeln!(f, "{}:{}: {}", self.line, self.column, self.message)?;
+        writeln!(f, "  {}", self.text)?;
+        write!(f, "  {}^", " ".repeat(self.column - 1))
+    }
+}
+
+impl error::Error for ParseError {}
+
+/// Parse `part` of `input` with `FromStr`, reporting failure at its position.
+pub fn parse_part<T: FromStr>(input: &str, part: &str) -> ParseResult<T>
+where
+    T::Err: fmt::Display,
+{
+    part.parse()
+        .map_err(|e| ParseError::at(input, part, format!("can't parse {:?}: {}", part, e)))
+}
@@ -0,0 +1,93 @@
+//! A small mutation fuzzer for the parsers, which runs on stable Rust.
+//!
+//! The `fuzz/` directory has coverage-guided `cargo fuzz` targets for the same entry points;
+//! this is the cheap version that runs as part of `cargo test`. It starts from generated
+//! inputs, mangles them in ways that tend to find parser bugs (huge numbers, deleted or
+//! repeated chunks, stray punctuation) and checks each day's parser returns rather than
+//! panics.
+
+use std::panic::{self, AssertUnwindSafe};
+
+use crate::gen::generate;
+use crate::pool::panic_message;
+use crate::rng::Rng;
+use crate::Day;
+
+/// Characters that mean something to at least one day's parser.
+const INTERESTING: &[u8] = b"0123456789-+ ,:;=[]$>\n\nabcxyzABCXYZ.#SE";
+
+/// Numbers that are too big, too small or otherwise awkward.
+const NUMBERS: &[&str] = &[
+    "0",
+    "-1",
+    "4294967296",
+    "2147483647",
+    "-2147483648",
+    "18446744073709551615",
+    "18446744073709551616",
+    "99999999999999999999999",
+];
+
+/// `input` with a few random changes, which may leave it invalid.
+pub fn mutate(rng: &mut Rng, input: &str) -> String {
+    let mut bytes = input.as_bytes().to_vec();
+    for _ in 0..rng.range(1..=4) {
+        let at = rng.range(0..=bytes.len());
+        let end = rng.range(at..=bytes.len().min(at + 16));
+        match rng.range(0..=5) {
+            0 => bytes.insert(at, *rng.pick(INTERESTING)),
+            1 => {
+                bytes.drain(at..end);
+            }
+            2 => {
+                let chunk = bytes[at..end].to_vec();
+                bytes.splice(at..at, chunk);
+            }
+            3 => {
+                // Replace the number starting near `at`, if there is one.
+                let start = (at..bytes.len()).find(|&i| bytes[i].is_ascii_digit());
+                if let Some(start) = start {
+                    let len = bytes[start..]
+                        .iter()
+                        .take_while(|b| b.is_ascii_digit())
+                        .count();
+                    let number = rng.pick(NUMBERS).bytes();
+                    bytes.splice(start..start + len, number);
+                }
+            }
+            4 => bytes.truncate(at),
+            _ => {
+                if at < bytes.len() {
+                    bytes[at] = *rng.pick(INTERESTING);
+                }
+            }
+        }
+    }
+    String::from_utf8_lossy(&bytes).into_owned()
+}
+
+/// Parse `input` as `day`, turning a panic into an error describing it.
+pub fn check(day: &Day, input: &str) -> Result<(), String> {
+    let result = panic::catch_unwind(AssertUnwindSafe(|| {
+        let _ = day.parse(input);
+    }));
+    result.map_err(|e| {
+        let message = panic_message(e.as_ref());
+        format!("day {} panicked: {}\n{}", day.number, message, input)
+    })
+}
+
+/// Parse `runs` mutated inputs for `day`, stopping at the first one that panics.
+pub fn fuzz(day: &Day, seed: u64, runs: usize) -> Result<(), String> {
+    let rng = &mut Rng::new(seed);
+    for run in 0..runs {
+        let base = generate(day.number, rng.next_u64(), rng.range(1..=20)).unwrap_or_default();
+        let mut input = mutate(rng, &base);
+        // Mutations pile up a bit, so some inputs end up a long way from valid.
+        while rng.chance(0.3) {
+            input = mutate(rng, &input);
+        }
+        check(day, &input).map_err(|e| format!("run {}: {}", run, e))?;
+    }
+    Ok(())
+}
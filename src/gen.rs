@@ -0,0 +1,554 @@
+//! Made-up puzzle inputs, for stress tests and benchmarks bigger than the bundled inputs.
+//!
+//! Every generator only produces inputs its day can solve: the parser accepts them and
+//! neither part panics. `size` is roughly the number of lines or items; each generator
+//! says what it means for its day.
+
+use std::collections::HashSet;
+use std::fmt::Write;
+
+use crate::geometry::{Bounds, Point, Vec2};
+use crate::rng::Rng;
+
+/// A random input for `day`, or `None` if there's no such day.
+pub fn generate(day: u32, seed: u64, size: usize) -> Option<String> {
+    let rng = &mut Rng::new(seed);
+    let size = size.max(1);
+    let input = match day {
+        1 => day1(rng, size),
+        2 => day2(rng, size),
+        3 => day3(rng, size),
+        4 => day4(rng, size),
+        5 => day5(rng, size),
+        6 => day6(rng, size),
+        7 => day7(rng, size),
+        8 => day8(rng, size),
+        9 => day9(rng, size),
+        10 => day10(rng, size),
+        11 => day11(rng, size),
+        12 => day12(rng, size),
+        13 => day13(rng, size),
+        14 => day14(rng, size),
+        15 => day15(rng, size),
+        16 => day16(rng, size),
+        _ => return None,
+    };
+    Some(input)
+}
+
+const LOWER: &[u8] = b"abcdefghijklmnopqrstuvwxyz";
+const LETTERS: &[u8] = b"abcdefghijklmnopqrstuvwxyzABCDEFGHIJKLMNOPQRSTUVWXYZ";
+
+/// `size` elves.
+fn day1(rng: &mut Rng, size: usize) -> String {
+    let elves = (0..size)
+        .map(|_| {
+            let snacks = rng.range(1..=15);
+            let mut elf = String::new();
+            for _ in 0..snacks {
+                writeln!(elf, "{}", rng.range(1000..=60_000)).unwrap();
+            }
+            elf
+        })
+        .collect::<Vec<_>>();
+    elves.join("\n")
+}
+
+/// `size` rounds.
+fn day2(rng: &mut Rng, size: usize) -> String {
+    let mut out = String::new();
+    for _ in 0..size {
+        let (a, b) = (rng.range(0..=2u8), rng.range(0..=2u8));
+        writeln!(out, "{} {}", (b'A' + a) as char, (b'X' + b) as char).unwrap();
+    }
+    out
+}
+
+/// `size` groups of three rucksacks.
+fn day3(rng: &mut Rng, size: usize) -> String {
+    let mut out = String::new();
+    for _ in 0..size {
+        // The badge is the only item all three carry, so each elf gets items from their
+        // own share of the other letters.
+        let mut letters = LETTERS.to_vec();
+        rng.shuffle(&mut letters);
+        let badge = letters.pop().unwrap();
+        for pool in letters.chunks(letters.len() / 3).take(3) {
+            let mut pool = pool.to_vec();
+            rng.shuffle(&mut pool);
+            // Exactly one item is in both compartments.
+            let common = if rng.chance(0.2) {
+                badge
+            } else {
+                pool.pop().unwrap()
+            };
+            let (left, right) = pool.split_at(pool.len() / 2);
+            let half = rng.range(2..=12);
+            let mut a = vec![common];
+            let mut b = vec![common];
+            if common != badge {
+                a.push(badge);
+            }
+            while a.len() < half {
+                a.push(*rng.pick(left));
+            }
+            while b.len() < half {
+                b.push(*rng.pick(right));
+            }
+            rng.shuffle(&mut a);
+            rng.shuffle(&mut b);
+            a.extend(b);
+            out.push_str(std::str::from_utf8(&a).unwrap());
+            out.push('\n');
+        }
+    }
+    out
+}
+
+/// `size` pairs of elves.
+fn day4(rng: &mut Rng, size: usize) -> String {
+    let mut out = String::new();
+    for _ in 0..size {
+        let mut range = || {
+            let start = rng.range(1..=99);
+            (start, rng.range(start..=99))
+        };
+        let (a, b) = (range(), range());
+        writeln!(out, "{}-{},{}-{}", a.0, a.1, b.0, b.1).unwrap();
+    }
+    out
+}
+
+/// `size` moves.
+fn day5(rng: &mut Rng, size: usize) -> String {
+    let count = rng.range(3..=9);
+    let mut stacks: Vec<Vec<u8>> = (0..count)
+        .map(|_| {
+            let height = rng.range(1..=8);
+            (0..height).map(|_| rng.range(b'A'..=b'Z')).collect()
+        })
+        .collect();
+
+    let mut out = String::new();
+    let tallest = stacks.iter().map(Vec::len).max().unwrap();
+    for level in (0..tallest).rev() {
+        let row = stacks
+            .iter()
+            .map(|s| match s.get(level) {
+                Some(&c) => format!("[{}]", c as char),
+                None => "   ".to_owned(),
+            })
+            .collect::<Vec<_>>();
+        writeln!(out, "{}", row.join(" ").trim_end()).unwrap();
+    }
+    let labels = (1..=count).map(|i| format!(" {} ", i)).collect::<Vec<_>>();
+    writeln!(out, "{}\n", labels.join(" ").trim_end()).unwrap();
+
+    // Play the moves out so none takes more crates than a stack has.
+    for _ in 0..size {
+        let from = loop {
+            let i = rng.range(0..=count - 1);
+            if !stacks[i].is_empty() {
+                break i;
+            }
+        };
+        let to = (from + rng.range(1..=count - 1)) % count;
+        let n = rng.range(1..=stacks[from].len().min(5));
+        let at = stacks[from].len() - n;
+        let moved = stacks[from].split_off(at);
+        stacks[to].extend(moved);
+        writeln!(out, "move {} from {} to {}", n, from + 1, to + 1).unwrap();
+    }
+    out
+}
+
+/// About `size` characters before the first marker.
+fn day6(rng: &mut Rng, size: usize) -> String {
+    // Three letters can't make a marker, so the first one comes after the prefix.
+    let mut out: String = (0..size).map(|_| *rng.pick(b"xyz") as char).collect();
+    let mut marker = LOWER.to_vec();
+    rng.shuffle(&mut marker);
+    out.extend(marker[..14].iter().map(|&c| c as char));
+    out.extend((0..size).map(|_| *rng.pick(LOWER) as char));
+    out.push('\n');
+    out
+}
+
+/// About `size` files.
+fn day7(rng: &mut Rng, size: usize) -> String {
+    struct Dir {
+        files: Vec<(String, u64)>,
+        dirs: Vec<(String, Dir)>,
+    }
+
+    fn name(rng: &mut Rng, taken: &mut HashSet<String>, ext: bool) -> String {
+        loop {
+            let len = rng.range(1..=8);
+            let mut name: String = (0..len).map(|_| *rng.pick(LOWER) as char).collect();
+            if ext {
+                name += *rng.pick(&["", ".txt", ".dat", ".log"]);
+            }
+            if taken.insert(name.clone()) {
+                return name;
+            }
+        }
+    }
+
+    fn build(rng: &mut Rng, files: &mut usize, depth: u32) -> Dir {
+        let mut taken = HashSet::new();
+        let mut dir = Dir {
+            files: Vec::new(),
+            dirs: Vec::new(),
+        };
+        let here = rng.range(0..=(*files).min(6));
+        for _ in 0..here {
+            dir.files.push((name(rng, &mut taken, true), 0));
+        }
+        *files -= here;
+        while *files > 0 && depth < 8 && rng.chance(0.6) {
+            let sub = build(rng, files, depth + 1);
+            dir.dirs.push((name(rng, &mut taken, false), sub));
+        }
+        dir
+    }
+
+    fn sizes(dir: &mut Dir, rng: &mut Rng, max: u64) {
+        for file in &mut dir.files {
+            file.1 = rng.range(1..=max);
+        }
+        for (_, sub) in &mut dir.dirs {
+            sizes(sub, rng, max);
+        }
+    }
+
+    fn write(dir: &Dir, out: &mut String) {
+        out.push_str("$ ls\n");
+        for (name, _) in &dir.dirs {
+            writeln!(out, "dir {}", name).unwrap();
+        }
+        for (name, size) in &dir.files {
+            writeln!(out, "{} {}", size, name).unwrap();
+        }
+        for (name, sub) in &dir.dirs {
+            writeln!(out, "$ cd {}", name).unwrap();
+            write(sub, out);
+            out.push_str("$ cd ..\n");
+        }
+    }
+
+    let mut files = size;
+    let mut root = build(rng, &mut files, 0);
+    while files > 0 {
+        let sub = build(rng, &mut files, 1);
+        root.dirs
+            .push((format!("more{}", root.dirs.len() + 1), sub));
+    }
+    // Part 2 needs the disk over 40,000,000 full, with a directory other than the root big
+    // enough to free the difference. The small files stay under 30,000,000 in total, so one
+    // big file in its own directory does it.
+    sizes(&mut root, rng, (30_000_000 / size as u64).clamp(1, 300_000));
+    let big = Dir {
+        files: vec![("big.img".to_owned(), rng.range(40_000_000..=50_000_000))],
+        dirs: Vec::new(),
+    };
+    // Generated names have no digits, so this can't clash.
+    root.dirs.push(("disk0".to_owned(), big));
+
+    let mut out = "$ cd /\n".to_owned();
+    write(&root, &mut out);
+    out
+}
+
+/// A `size` by `size` forest.
+fn day8(rng: &mut Rng, size: usize) -> String {
+    let mut out = String::new();
+    for _ in 0..size {
+        out.extend((0..size).map(|_| (b'0' + rng.range(0..=9u8)) as char));
+        out.push('\n');
+    }
+    out
+}
+
+/// `size` motions.
+fn day9(rng: &mut Rng, size: usize) -> String {
+    let mut out = String::new();
+    for _ in 0..size {
+        writeln!(
+            out,
+            "{} {}",
+            rng.pick(&["U", "D", "L", "R"]),
+            rng.range(1..=20)
+        )
+        .unwrap();
+    }
+    out
+}
+
+/// `size` instructions.
+fn day10(rng: &mut Rng, size: usize) -> String {
+    let mut out = String::new();
+    for _ in 0..size {
+        if rng.chance(0.3) {
+            out.push_str("noop\n");
+        } else {
+            writeln!(out, "addx {}", rng.range(-12..=12)).unwrap();
+        }
+    }
+    out
+}
+
+/// About `size` items, spread over up to eight monkeys.
+fn day11(rng: &mut Rng, size: usize) -> String {
+    let count = (2 + size / 8).min(8);
+    let mut primes = vec![2u64, 3, 5, 7, 11, 13, 17, 19, 23];
+    rng.shuffle(&mut primes);
+
+    let mut blocks = Vec::new();
+    for (i, divisor) in primes.iter().enumerate().take(count) {
+        let items = (0..(size / count).max(1))
+            .map(|_| rng.range(50..=99).to_string())
+            .collect::<Vec<_>>();
+        // Multiplying by at most 3 and then dividing by 3 means part 1's worry levels can't
+        // grow without bound, and part 2's stay under the product of the divisors times 3.
+        let op = match rng.range(0..=3) {
+            0 => "* 2".to_owned(),
+            1 => "* 3".to_owned(),
+            2 => "+ old".to_owned(),
+            _ => format!("+ {}", rng.range(1..=9)),
+        };
+        let target_true = (i + rng.range(1..=count - 1)) % count;
+        let target_false = loop {
+            let t = rng.range(0..=count - 1);
+            if t != i && (t != target_true || count == 2) {
+                break t;
+            }
+        };
+        blocks.push(format!(
+            "Monkey {}:
+  Starting items: {}
+  Operation: new = old {}
+  Test: divisible by {}
+    If true: throw to monkey {}
+    If false: throw to monkey {}
+",
+            i,
+            items.join(", "),
+            op,
+            divisor,
+            target_true,
+            target_false
+        ));
+    }
+    blocks.join("\n")
+}
+
+/// A heightmap `size` squares wide, at least 26 so the climb can be gentle enough.
+fn day12(rng: &mut Rng, size: usize) -> String {
+    let (width, height) = (size.max(26), (size / 2).max(5));
+    let mut rows: Vec<Vec<u8>> = (0..height)
+        .map(|_| (0..width).map(|_| *rng.pick(LOWER)).collect())
+        .collect();
+
+    // Carve a path from S to E that never climbs more than one step at a time. It crosses
+    // each column in one straight run, so it never crosses itself.
+    let mut path = Vec::new();
+    let mut y = rng.range(0..=height - 1);
+    for x in 0..width {
+        let to = rng.range(0..=height - 1);
+        while y != to {
+            path.push((x, y));
+            y = if to > y { y + 1 } else { y - 1 };
+        }
+        path.push((x, y));
+    }
+    let last = path.len() - 1;
+    for (i, &(x, y)) in path.iter().enumerate() {
+        rows[y][x] = b'a' + (i * 25 / last) as u8;
+    }
+    let ((sx, sy), (ex, ey)) = (path[0], path[last]);
+    rows[sy][sx] = b'S';
+    rows[ey][ex] = b'E';
+
+    let mut out = String::new();
+    for row in rows {
+        out.push_str(std::str::from_utf8(&row).unwrap());
+        out.push('\n');
+    }
+    out
+}
+
+/// `size` pairs of packets.
+fn day13(rng: &mut Rng, size: usize) -> String {
+    fn packet(rng: &mut Rng, depth: u32, out: &mut String) {
+        out.push('[');
+        let len = rng.range(0..=5);
+        for i in 0..len {
+            if i > 0 {
+                out.push(',');
+            }
+            if depth < 4 && rng.chance(0.3) {
+                packet(rng, depth + 1, out);
+            } else {
+                write!(out, "{}", rng.range(0..=10)).unwrap();
+            }
+        }
+        out.push(']');
+    }
+
+    let pairs = (0..size)
+        .map(|_| {
+            let mut pair = String::new();
+            packet(rng, 0, &mut pair);
+            pair.push('\n');
+            packet(rng, 0, &mut pair);
+            pair.push('\n');
+            pair
+        })
+        .collect::<Vec<_>>();
+    pairs.join("\n")
+}
+
+/// `size` rock paths.
+fn day14(rng: &mut Rng, size: usize) -> String {
+    // Sand piled up to the source would be wider than any rock `W` below it, so part 1's
+    // sand always ends up falling past the rocks.
+    const W: i32 = 40;
+    let bounds = Bounds {
+        min: Point::new(500 - W, W + 1),
+        max: Point::new(500 + W, W + 10 + size as i32),
+    };
+    let mut out = String::new();
+    for _ in 0..size {
+        let mut p = Point::new(
+            rng.range(bounds.min.x..=bounds.max.x),
+            rng.range(bounds.min.y..=bounds.max.y),
+        );
+        let mut points = vec![p.to_string()];
+        let mut horizontal = rng.chance(0.5);
+        for _ in 0..rng.range(1..=4) {
+            let len = rng.range(1..=8) * if rng.chance(0.5) { 1 } else { -1 };
+            p += if horizontal {
+                Vec2::new(len, 0)
+            } else {
+                Vec2::new(0, len)
+            };
+            p.x = p.x.clamp(bounds.min.x, bounds.max.x);
+            p.y = p.y.clamp(bounds.min.y, bounds.max.y);
+            points.push(p.to_string());
+            horizontal = !horizontal;
+        }
+        points.dedup();
+        if points.len() == 1 {
+            points.push(points[0].clone());
+        }
+        writeln!(out, "{}", points.join(" -> ")).unwrap();
+    }
+    out
+}
+
+/// `size` sensors, plus four that leave exactly one gap in the default search area.
+fn day15(rng: &mut Rng, size: usize) -> String {
+    const MAX: i32 = 4_000_000;
+    let hidden = Point::new(rng.range(0..=MAX), rng.range(0..=MAX));
+    let mut lines = Vec::new();
+    let mut line = |sensor: Point, beacon: Point| {
+        lines.push(format!(
+            "Sensor at x={}, y={}: closest beacon is at x={}, y={}",
+            sensor.x, sensor.y, beacon.x, beacon.y
+        ));
+    };
+
+    // A sensor `k` away diagonally with range `2k - 1` covers every point within `k` of the
+    // gap on its side, except the gap itself. With `k` past the search area, four of them
+    // cover all of it.
+    let k = MAX + 1;
+    for (dx, dy) in [(1, 1), (1, -1), (-1, 1), (-1, -1)] {
+        let sensor = hidden + Vec2::new(dx * k, dy * k);
+        line(sensor, sensor + Vec2::new(-dx * (2 * k - 1), 0));
+    }
+
+    // The rest are decoys whose beacons are closer than the gap.
+    for _ in 0..size {
+        let sensor = Point::new(
+            rng.range(-MAX / 2..=MAX * 3 / 2),
+            rng.range(-MAX / 2..=MAX * 3 / 2),
+        );
+        let reach = (sensor.manhattan_to(hidden) - 1).min(MAX / 2);
+        if reach < 1 {
+            continue;
+        }
+        let dist = rng.range(1..=reach);
+        let dx = rng.range(0..=dist);
+        let offset = Vec2::new(
+            if rng.chance(0.5) { dx } else { -dx },
+            if rng.chance(0.5) {
+                dist - dx
+            } else {
+                dx - dist
+            },
+        );
+        line(sensor, sensor + offset);
+    }
+    rng.shuffle(&mut lines);
+    lines.join("\n") + "\n"
+}
+
+/// `size` valves, up to a third of them worth opening but never more than 8. Each extra one
+/// makes the main part 2 search about ten times slower: 8 take about a second in release, 10
+/// about a minute.
+fn day16(rng: &mut Rng, size: usize) -> String {
+    let count = size.clamp(2, 26 * 26);
+    let mut names = vec!["AA".to_owned()];
+    let mut taken: HashSet<String> = names.iter().cloned().collect();
+    while names.len() < count {
+        let name: String = (0..2).map(|_| rng.range(b'A'..=b'Z') as char).collect();
+        if taken.insert(name.clone()) {
+            names.push(name);
+        }
+    }
+
+    // A random tree keeps every valve reachable; a few more tunnels add loops.
+    let mut tunnels: Vec<Vec<usize>> = vec![Vec::new(); count];
+    let mut connect = |a: usize, b: usize| {
+        if a != b && !tunnels[a].contains(&b) {
+            tunnels[a].push(b);
+            tunnels[b].push(a);
+        }
+    };
+    for i in 1..count {
+        connect(i, rng.range(0..=i - 1));
+    }
+    for _ in 0..count / 2 {
+        connect(rng.range(0..=count - 1), rng.range(0..=count - 1));
+    }
+
+    let mut rates = vec![0; count];
+    let mut worth = (1..count).collect::<Vec<_>>();
+    rng.shuffle(&mut worth);
+    for &i in worth.iter().take((count / 3).clamp(1, 8)) {
+        rates[i] = rng.range(1..=25);
+    }
+
+    let mut lines = (0..count)
+        .map(|i| {
+            let targets = tunnels[i]
+                .iter()
+                .map(|&t| names[t].as_str())
+                .collect::<Vec<_>>();
+            let tunnels = if targets.len() == 1 {
+                "tunnel leads to valve"
+            } else {
+                "tunnels lead to valves"
+            };
+            format!(
+                "Valve {} has flow rate={}; {} {}",
+                names[i],
+                rates[i],
+                tunnels,
+                targets.join(", ")
+            )
+        })
+        .collect::<Vec<_>>();
+    rng.shuffle(&mut lines);
+    lines.join("\n") + "\n"
+}
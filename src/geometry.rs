@@ -0,0 +1,230 @@
+//! Points and offsets on an integer plane, with `y` growing downwards.
+
+use std::fmt;
+use std::ops::{Add, AddAssign, Mul, Neg, Sub, SubAssign};
+use std::str::FromStr;
+
+#[derive(Debug, Default, Copy, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Vec2 {
+    pub x: i32,
+    pub y: i32,
+}
+
+/// A position, as opposed to an offset between positions.
+pub type Point = Vec2;
+
+impl Vec2 {
+    pub const ZERO: Vec2 = Vec2::new(0, 0);
+
+    /// Offsets to the four orthogonal neighbours: up, down, left, right.
+    pub const ORTHOGONAL: [Vec2; 4] = [
+        Vec2::new(0, -1),
+        Vec2::new(0, 1),
+        Vec2::new(-1, 0),
+        Vec2::new(1, 0),
+    ];
+
+    /// Offsets to all eight neighbours, clockwise from up.
+    pub const ALL_AROUND: [Vec2; 8] = [
+        Vec2::new(0, -1),
+        Vec2::new(1, -1),
+        Vec2::new(1, 0),
+        Vec2::new(1, 1),
+        Vec2::new(0, 1),
+        Vec2::new(-1, 1),
+        Vec2::new(-1, 0),
+        Vec2::new(-1, -1),
+    ];
+
+    pub const fn new(x: i32, y: i32) -> Self {
+        Vec2 { x, y }
+    }
+
+    /// Each component reduced to -1, 0 or 1.
+    pub fn signum(self) -> Self {
+        Vec2::new(self.x.signum(), self.y.signum())
+    }
+
+    /// Length when moving only orthogonally.
+    pub fn manhattan(self) -> i32 {
+        self.x.abs() + self.y.abs()
+    }
+
+    /// Length when diagonal moves count as one step.
+    pub fn chebyshev(self) -> i32 {
+        self.x.abs().max(self.y.abs())
+    }
+
+    pub fn manhattan_to(self, other: Point) -> i32 {
+        (other - self).manhattan()
+    }
+
+    pub fn chebyshev_to(self, other: Point) -> i32 {
+        (other - self).chebyshev()
+    }
+
+    /// One step towards `target`, diagonally if needed.
+    pub fn step_towards(self, target: Point) -> Self {
+        self + (target - self).signum()
+    }
+}
+
+impl Add for Vec2 {
+    type Output = Vec2;
+
+    fn add(self, o: Vec2) -> Vec2 {
+        Vec2::new(self.x + o.x, self.y + o.y)
+    }
+}
+
+impl Sub for Vec2 {
+    type Output = Vec2;
+
+    fn sub(self, o: Vec2) -> Vec2 {
+        Vec2::new(self.x - o.x, self.y - o.y)
+    }
+}
+
+impl Mul<i32> for Vec2 {
+    type Output = Vec2;
+
+    fn mul(self, n: i32) -> Vec2 {
+        Vec2::new(self.x * n, self.y * n)
+    }
+}
+
+impl Neg for Vec2 {
+    type Output = Vec2;
+
+    fn neg(self) -> Vec2 {
+        Vec2::new(-self.x, -self.y)
+    }
+}
+
+impl AddAssign for Vec2 {
+    fn add_assign(&mut self, o: Vec2) {
+        *self = *self + o;
+    }
+}
+
+impl SubAssign for Vec2 {
+    fn sub_assign(&mut self, o: Vec2) {
+        *self = *self - o;
+    }
+}
+
+impl fmt::Display for Vec2 {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{},{}", self.x, self.y)
+    }
+}
+
+/// Parses `x,y`, like `498,4`.
+impl FromStr for Vec2 {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (x, y) = s.split_once(',').ok_or("expected a point like 498,4")?;
+        let num = |n: &str| n.trim().parse().map_err(|e| format!("{}: {:?}", e, n));
+        Ok(Vec2::new(num(x)?, num(y)?))
+    }
+}
+
+/// An orthogonal direction.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum Dir {
+    Up,
+    Down,
+    Left,
+    Right,
+}
+
+impl Dir {
+    pub const ALL: [Dir; 4] = [Dir::Up, Dir::Down, Dir::Left, Dir::Right];
+
+    pub fn offset(self) -> Vec2 {
+        match self {
+            Dir::Up => Vec2::new(0, -1),
+            Dir::Down => Vec2::new(0, 1),
+            Dir::Left => Vec2::new(-1, 0),
+            Dir::Right => Vec2::new(1, 0),
+        }
+    }
+
+    pub fn opposite(self) -> Dir {
+        match self {
+            Dir::Up => Dir::Down,
+            Dir::Down => Dir::Up,
+            Dir::Left => Dir::Right,
+            Dir::Right => Dir::Left,
+        }
+    }
+
+    pub fn turn_left(self) -> Dir {
+        match self {
+            Dir::Up => Dir::Left,
+            Dir::Left => Dir::Down,
+            Dir::Down => Dir::Right,
+            Dir::Right => Dir::Up,
+        }
+    }
+
+    pub fn turn_right(self) -> Dir {
+        self.turn_left().opposite()
+    }
+}
+
+/// Parses the initials `U`, `D`, `L` and `R`.
+impl FromStr for Dir {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "U" => Ok(Dir::Up),
+            "D" => Ok(Dir::Down),
+            "L" => Ok(Dir::Left),
+            "R" => Ok(Dir::Right),
+            _ => Err("expected U, D, L or R".to_owned()),
+        }
+    }
+}
+
+/// The smallest rectangle containing some points, including its edges.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct Bounds {
+    pub min: Point,
+    pub max: Point,
+}
+
+impl Bounds {
+    pub fn around(p: Point) -> Self {
+        Bounds { min: p, max: p }
+    }
+
+    /// The bounds of `points`, or `None` if there aren't any.
+    pub fn of(points: impl IntoIterator<Item = Point>) -> Option<Self> {
+        let mut points = points.into_iter();
+        let first = points.next()?;
+        Some(points.fold(Bounds::around(first), Bounds::including))
+    }
+
+    /// Grow to include `p`.
+    pub fn including(self, p: Point) -> Self {
+        Bounds {
+            min: Point::new(self.min.x.min(p.x), self.min.y.min(p.y)),
+            max: Point::new(self.max.x.max(p.x), self.max.y.max(p.y)),
+        }
+    }
+
+    pub fn contains(&self, p: Point) -> bool {
+        (self.min.x..=self.max.x).contains(&p.x) && (self.min.y..=self.max.y).contains(&p.y)
+    }
+
+    pub fn width(&self) -> i32 {
+        self.max.x - self.min.x + 1
+    }
+
+    pub fn height(&self) -> i32 {
+        self.max.y - self.min.y + 1
+    }
+}
@@ -0,0 +1,170 @@
+//! A rectangular grid of cells, stored row by row.
+
+use std::ops::{Index, IndexMut};
+
+use crate::geometry::{Point, Vec2};
+use crate::{ParseError, ParseResult};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Grid<T> {
+    width: usize,
+    height: usize,
+    cells: Vec<T>,
+}
+
+impl<T: Clone> Grid<T> {
+    pub fn new(width: usize, height: usize, fill: T) -> Self {
+        Grid {
+            width,
+            height,
+            cells: vec![fill; width * height],
+        }
+    }
+}
+
+impl<T> Grid<T> {
+    /// Parse a map with one character per cell, such as a heightmap.
+    ///
+    /// `cell` turns each character into a cell, or says what's wrong with it. Every line
+    /// must be the same width, and there must be at least one cell.
+    pub fn parse<F, E>(input: &str, mut cell: F) -> ParseResult<Self>
+    where
+        F: FnMut(Point, char) -> Result<T, E>,
+        E: Into<String>,
+    {
+        let mut width = None;
+        let mut height = 0;
+        let mut cells = Vec::new();
+        for line in input.lines() {
+            let mut x = 0;
+            for (i, c) in line.char_indices() {
+                let value = cell(Point::new(x, height as i32), c)
+                    .map_err(|e| ParseError::at_offset(line, i, e).on_line(height + 1))?;
+                cells.push(value);
+                x += 1;
+            }
+            if *width.get_or_insert(x as usize) != x as usize {
+                return Err(ParseError::at(
+                    input,
+                    line,
+                    "rows must all be the same width",
+                ));
+            }
+            height += 1;
+        }
+        match width {
+            Some(width) if width > 0 => Ok(Grid {
+                width,
+                height,
+                cells,
+            }),
+            _ => Err(ParseError::at_end(input, "empty map")),
+        }
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn contains(&self, p: Point) -> bool {
+        p.x >= 0 && (p.x as usize) < self.width && p.y >= 0 && (p.y as usize) < self.height
+    }
+
+    fn index_of(&self, p: Point) -> Option<usize> {
+        self.contains(p)
+            .then(|| p.y as usize * self.width + p.x as usize)
+    }
+
+    pub fn get(&self, p: Point) -> Option<&T> {
+        self.index_of(p).map(|i| &self.cells[i])
+    }
+
+    pub fn get_mut(&mut self, p: Point) -> Option<&mut T> {
+        self.index_of(p).map(|i| &mut self.cells[i])
+    }
+
+    /// Every position, row by row.
+    pub fn positions(&self) -> impl Iterator<Item = Point> {
+        let (width, height) = (self.width as i32, self.height as i32);
+        (0..height).flat_map(move |y| (0..width).map(move |x| Point::new(x, y)))
+    }
+
+    /// Every cell with its position, row by row.
+    pub fn iter(&self) -> impl Iterator<Item = (Point, &T)> {
+        self.positions().zip(self.cells.iter())
+    }
+
+    /// The first position whose cell matches.
+    pub fn find(&self, f: impl Fn(&T) -> bool) -> Option<Point> {
+        self.iter().find(|(_, c)| f(c)).map(|(p, _)| p)
+    }
+
+    /// The positions one step from `p` in each direction of `steps` that are inside the grid.
+    pub fn neighbours<'a>(
+        &'a self,
+        p: Point,
+        steps: &'a [Vec2],
+    ) -> impl Iterator<Item = Point> + 'a {
+        steps
+            .iter()
+            .map(move |&s| p + s)
+            .filter(|&n| self.contains(n))
+    }
+
+    /// The up to four orthogonal neighbours of `p`.
+    pub fn neighbours4(&self, p: Point) -> impl Iterator<Item = Point> + '_ {
+        self.neighbours(p, &Vec2::ORTHOGONAL)
+    }
+
+    /// The up to eight neighbours of `p`, including diagonals.
+    pub fn neighbours8(&self, p: Point) -> impl Iterator<Item = Point> + '_ {
+        self.neighbours(p, &Vec2::ALL_AROUND)
+    }
+
+    /// The positions in row `y`, left to right.
+    pub fn row(&self, y: i32) -> impl DoubleEndedIterator<Item = Point> {
+        (0..self.width as i32).map(move |x| Point::new(x, y))
+    }
+
+    /// The positions in column `x`, top to bottom.
+    pub fn column(&self, x: i32) -> impl DoubleEndedIterator<Item = Point> {
+        (0..self.height as i32).map(move |y| Point::new(x, y))
+    }
+
+    /// The positions walking away from `from` by `step`, not including `from`, until leaving
+    /// the grid.
+    pub fn ray(&self, from: Point, step: Vec2) -> impl Iterator<Item = Point> + '_ {
+        std::iter::successors(Some(from + step), move |&p| Some(p + step))
+            .take_while(|&p| self.contains(p))
+    }
+
+    /// Draw the grid with one character per cell, and a newline after each row.
+    pub fn render(&self, f: impl Fn(&T) -> char) -> String {
+        let mut out = String::with_capacity((self.width + 1) * self.height);
+        for row in self.cells.chunks(self.width.max(1)) {
+            out.extend(row.iter().map(&f));
+            out.push('\n');
+        }
+        out
+    }
+}
+
+impl<T> Index<Point> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, p: Point) -> &T {
+        self.get(p)
+            .unwrap_or_else(|| panic!("{:?} is outside the grid", p))
+    }
+}
+
+impl<T> IndexMut<Point> for Grid<T> {
+    fn index_mut(&mut self, p: Point) -> &mut T {
+        self.get_mut(p)
+            .unwrap_or_else(|| panic!("{:?} is outside the grid", p))
+    }
+}
@@ -0,0 +1,266 @@
+//! Pictures of puzzle states, saved as PPM or PNG without any image library.
+
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+use crate::geometry::Point;
+use crate::grid::Grid;
+use crate::vis::{Frame, Viewer};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Rgb(pub u8, pub u8, pub u8);
+
+impl Rgb {
+    pub const BLACK: Rgb = Rgb(0, 0, 0);
+    pub const WHITE: Rgb = Rgb(255, 255, 255);
+    pub const RED: Rgb = Rgb(220, 40, 40);
+
+    /// Between `self` at 0 and `other` at 1.
+    pub fn mix(self, other: Rgb, t: f64) -> Rgb {
+        let t = t.clamp(0.0, 1.0);
+        let c = |a: u8, b: u8| (a as f64 + (b as f64 - a as f64) * t).round() as u8;
+        Rgb(c(self.0, other.0), c(self.1, other.1), c(self.2, other.2))
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Image {
+    width: usize,
+    height: usize,
+    pixels: Vec<Rgb>,
+}
+
+impl Image {
+    pub fn new(width: usize, height: usize, fill: Rgb) -> Self {
+        Image {
+            width,
+            height,
+            pixels: vec![fill; width * height],
+        }
+    }
+
+    /// One pixel per grid cell.
+    pub fn from_grid<T>(grid: &Grid<T>, f: impl Fn(Point, &T) -> Rgb) -> Self {
+        let mut image = Image::new(grid.width(), grid.height(), Rgb::BLACK);
+        for p in grid.positions() {
+            image.set(p.x as usize, p.y as usize, f(p, &grid[p]));
+        }
+        image
+    }
+
+    /// One pixel per character of `text`, with short lines padded by `f(' ')`.
+    pub fn from_text(text: &str, f: impl Fn(char) -> Rgb) -> Self {
+        let width = text.lines().map(|l| l.chars().count()).max().unwrap_or(0);
+        let height = text.lines().count();
+        let mut image = Image::new(width, height, f(' '));
+        for (y, line) in text.lines().enumerate() {
+            for (x, c) in line.chars().enumerate() {
+                image.set(x, y, f(c));
+            }
+        }
+        image
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn get(&self, x: usize, y: usize) -> Rgb {
+        self.pixels[y * self.width + x]
+    }
+
+    pub fn set(&mut self, x: usize, y: usize, c: Rgb) {
+        self.pixels[y * self.width + x] = c;
+    }
+
+    /// Each pixel blown up to a `factor` by `factor` square, so small grids are visible.
+    pub fn scaled(&self, factor: usize) -> Self {
+        let factor = factor.max(1);
+        let mut image = Image::new(self.width * factor, self.height * factor, Rgb::BLACK);
+        for y in 0..image.height {
+            for x in 0..image.width {
+                image.set(x, y, self.get(x / factor, y / factor));
+            }
+        }
+        image
+    }
+
+    /// Binary PPM (P6), which most viewers open and is trivial to write.
+    pub fn to_ppm(&self) -> Vec<u8> {
+        let mut out = format!("P6\n{} {}\n255\n", self.width, self.height).into_bytes();
+        for p in &self.pixels {
+            out.extend([p.0, p.1, p.2]);
+        }
+        out
+    }
+
+    /// An 8-bit RGB PNG. The pixel data is stored rather than compressed, which keeps the
+    /// encoder tiny at the cost of bigger files.
+    pub fn to_png(&self) -> Vec<u8> {
+        let mut out = b"\x89PNG\r\n\x1a\n".to_vec();
+
+        let mut header = Vec::new();
+        header.extend((self.width as u32).to_be_bytes());
+        header.extend((self.height as u32).to_be_bytes());
+        // Bit depth 8, colour type 2 (RGB), default compression, filter and interlacing.
+        header.extend([8, 2, 0, 0, 0]);
+        png_chunk(&mut out, b"IHDR", &header);
+
+        // Each row starts with its filter type, which is always 0 (none).
+        let mut raw = Vec::with_capacity((self.width * 3 + 1) * self.height);
+        for row in self.pixels.chunks(self.width.max(1)).take(self.height) {
+            raw.push(0);
+            for p in row {
+                raw.extend([p.0, p.1, p.2]);
+            }
+        }
+        png_chunk(&mut out, b"IDAT", &zlib_stored(&raw));
+        png_chunk(&mut out, b"IEND", &[]);
+        out
+    }
+
+    /// Save as PNG or PPM, going by the file extension.
+    pub fn save(&self, path: &Path) -> io::Result<()> {
+        let data = match path.extension().and_then(|e| e.to_str()) {
+            Some("png") => self.to_png(),
+            Some("ppm") => self.to_ppm(),
+            _ => {
+                let msg = format!("{}: expected a .png or .ppm file", path.display());
+                return Err(io::Error::new(io::ErrorKind::InvalidInput, msg));
+            }
+        };
+        fs::write(path, data)
+    }
+}
+
+/// A colour for each character in a `Frame`, so the terminal views can be saved too.
+pub fn frame_colour(c: char) -> Rgb {
+    match c {
+        ' ' | '.' => Rgb(15, 15, 30),
+        '#' => Rgb(170, 170, 170),
+        'o' => Rgb(230, 200, 90),
+        '+' | 'H' => Rgb::RED,
+        's' => Rgb(60, 200, 60),
+        c if c.is_ascii_digit() => Rgb(240, 140, 40),
+        // Anything else, like crate letters, gets its own colour.
+        c => {
+            let h = c as u32;
+            Rgb(
+                (80 + h * 37 % 176) as u8,
+                (80 + h * 71 % 176) as u8,
+                (80 + h * 113 % 176) as u8,
+            )
+        }
+    }
+}
+
+/// Saves every `every`th frame a simulation shows as a numbered image.
+pub struct FrameWriter {
+    /// Where to save frames, without the number and extension.
+    prefix: PathBuf,
+    extension: &'static str,
+    scale: usize,
+    every: usize,
+    steps: usize,
+    pub written: Vec<PathBuf>,
+    /// The first save that failed, after which nothing more is written.
+    pub error: Option<io::Error>,
+}
+
+impl FrameWriter {
+    pub fn new(prefix: PathBuf, extension: &'static str, scale: usize, every: usize) -> Self {
+        FrameWriter {
+            prefix,
+            extension,
+            scale,
+            every: every.max(1),
+            steps: 0,
+            written: Vec::new(),
+            error: None,
+        }
+    }
+
+    fn write(&mut self, frame: &Frame) -> io::Result<()> {
+        let name = format!(
+            "{}-{:05}.{}",
+            self.prefix.display(),
+            self.steps,
+            self.extension
+        );
+        let path = PathBuf::from(name);
+        Image::from_text(&frame.rows.join("\n"), frame_colour)
+            .scaled(self.scale)
+            .save(&path)?;
+        self.written.push(path);
+        Ok(())
+    }
+}
+
+impl Viewer for FrameWriter {
+    fn show(&mut self, draw: &mut dyn FnMut() -> Frame) {
+        if self.error.is_none() && self.steps.is_multiple_of(self.every) {
+            if let Err(e) = self.write(&draw()) {
+                self.error = Some(e);
+            }
+        }
+        self.steps += 1;
+    }
+}
+
+fn png_chunk(out: &mut Vec<u8>, kind: &[u8; 4], data: &[u8]) {
+    out.extend((data.len() as u32).to_be_bytes());
+    let start = out.len();
+    out.extend(kind);
+    out.extend(data);
+    let crc = crc32(&out[start..]);
+    out.extend(crc.to_be_bytes());
+}
+
+/// `data` wrapped as a zlib stream of uncompressed deflate blocks.
+fn zlib_stored(data: &[u8]) -> Vec<u8> {
+    // Deflate with a 32K window, no preset dictionary; 0x7801 is a multiple of 31 as required.
+    let mut out = vec![0x78, 0x01];
+    let mut blocks = data.chunks(u16::MAX as usize).peekable();
+    if blocks.peek().is_none() {
+        out.extend([1, 0, 0, 0xff, 0xff]);
+    }
+    while let Some(block) = blocks.next() {
+        let last = blocks.peek().is_none();
+        out.push(last as u8);
+        let len = block.len() as u16;
+        out.extend(len.to_le_bytes());
+        out.extend((!len).to_le_bytes());
+        out.extend(block);
+    }
+    out.extend(adler32(data).to_be_bytes());
+    out
+}
+
+pub fn crc32(data: &[u8]) -> u32 {
+    let mut crc = !0u32;
+    for &b in data {
+        crc ^= b as u32;
+        for _ in 0..8 {
+            crc = if crc & 1 != 0 {
+                (crc >> 1) ^ 0xedb8_8320
+            } else {
+                crc >> 1
+            };
+        }
+    }
+    !crc
+}
+
+pub fn adler32(data: &[u8]) -> u32 {
+    let (mut a, mut b) = (1u32, 0u32);
+    for &byte in data {
+        a = (a + byte as u32) % 65521;
+        b = (b + a) % 65521;
+    }
+    (b << 16) | a
+}
@@ -0,0 +1,294 @@
+use std::fmt::Display;
+use std::fs;
+use std::io::{self, BufRead, Read};
+use std::path::{Path, PathBuf};
+
+pub mod answers;
+pub mod days;
+pub mod error;
+pub mod fuzz;
+pub mod gen;
+pub mod geometry;
+pub mod grid;
+pub mod image;
+pub mod params;
+pub mod pool;
+pub mod report;
+pub mod rng;
+pub mod vis;
+
+pub use error::{ParseError, ParseResult};
+pub use image::Image;
+pub use params::Params;
+pub use vis::Viewer;
+
+pub type BoxErr = Box<dyn std::error::Error>;
+pub type BoxResult<T> = Result<T, BoxErr>;
+
+/// A day's puzzle: parse the input once, then answer both parts from it.
+pub trait Solution: Sized {
+    type Part1: Display;
+    type Part2: Display;
+
+    fn parse(input: &str) -> ParseResult<Self>;
+    fn part1(&self) -> Self::Part1;
+    fn part2(&self) -> Self::Part2;
+
+    fn parse_reader<R: BufRead>(mut reader: R) -> BoxResult<Self> {
+        let mut input = String::new();
+        reader.read_to_string(&mut input)?;
+        Ok(Self::parse(&input)?)
+    }
+
+    /// Names of the puzzle constants `configure` understands.
+    const PARAMS: &'static [&'static str] = &[];
+
+    /// Replace puzzle constants with any given in `params`.
+    fn configure(&mut self, _params: &Params) -> Result<(), String> {
+        Ok(())
+    }
+
+    /// Other ways of solving the puzzle, which should give the same answers as `part1` and
+    /// `part2`.
+    fn variants() -> Vec<Variant<Self>> {
+        Vec::new()
+    }
+
+    /// Answer `part` while showing each step of the simulation behind it, for days that have
+    /// one.
+    fn visualize(&self, _part: u32, _viewer: &mut dyn Viewer) -> Option<String> {
+        None
+    }
+
+    /// Pictures of the puzzle's state, named for what they show, for days that have any.
+    fn pictures(&self) -> Vec<(&'static str, Image)> {
+        Vec::new()
+    }
+}
+
+/// Another solver for one or both parts of a day, such as a brute-force reference for a clever
+/// solution. `aoc crosscheck` runs them all on the same input and reports any disagreement.
+pub struct Variant<S: Solution> {
+    pub name: &'static str,
+    pub part1: Option<fn(&S) -> S::Part1>,
+    pub part2: Option<fn(&S) -> S::Part2>,
+    /// Whether the variant can solve this input, for ones that only work on small inputs.
+    pub handles: fn(&S) -> bool,
+}
+
+/// A parsed input with its answers erased to strings, so days can share a registry.
+pub trait Answers {
+    fn part1(&self) -> String;
+    fn part2(&self) -> String;
+    fn configure(&mut self, params: &Params) -> Result<(), String>;
+    /// Answer `part` with the variant called `name`, if it solves that part of this input.
+    fn variant(&self, name: &str, part: u32) -> Option<String>;
+    /// Whether the variant called `name` can solve this input.
+    fn handles(&self, name: &str) -> bool;
+    fn visualize(&self, part: u32, viewer: &mut dyn Viewer) -> Option<String>;
+    fn pictures(&self) -> Vec<(&'static str, Image)>;
+
+    fn part(&self, part: u32) -> String {
+        if part == 1 {
+            self.part1()
+        } else {
+            self.part2()
+        }
+    }
+}
+
+impl<S: Solution> Answers for S {
+    fn part1(&self) -> String {
+        Solution::part1(self).to_string()
+    }
+
+    fn part2(&self) -> String {
+        Solution::part2(self).to_string()
+    }
+
+    fn configure(&mut self, params: &Params) -> Result<(), String> {
+        Solution::configure(self, params)
+    }
+
+    fn variant(&self, name: &str, part: u32) -> Option<String> {
+        let variant = S::variants().into_iter().find(|v| v.name == name)?;
+        if !(variant.handles)(self) {
+            return None;
+        }
+        if part == 1 {
+            variant.part1.map(|f| f(self).to_string())
+        } else {
+            variant.part2.map(|f| f(self).to_string())
+        }
+    }
+
+    fn handles(&self, name: &str) -> bool {
+        S::variants()
+            .into_iter()
+            .any(|v| v.name == name && (v.handles)(self))
+    }
+
+    fn visualize(&self, part: u32, viewer: &mut dyn Viewer) -> Option<String> {
+        Solution::visualize(self, part, viewer)
+    }
+
+    fn pictures(&self) -> Vec<(&'static str, Image)> {
+        Solution::pictures(self)
+    }
+}
+
+type ParseFn = fn(&str) -> ParseResult<Box<dyn Answers>>;
+
+#[derive(Clone, Copy)]
+pub struct Day {
+    pub number: u32,
+    pub title: &'static str,
+    /// Names of the puzzle constants that can be changed with `Params`.
+    pub params: &'static [&'static str],
+    parse: ParseFn,
+    variants: fn() -> Vec<&'static str>,
+}
+
+fn parse_boxed<S: Solution + 'static>(input: &str) -> ParseResult<Box<dyn Answers>> {
+    Ok(Box::new(S::parse(input)?))
+}
+
+fn variant_names<S: Solution>() -> Vec<&'static str> {
+    S::variants().iter().map(|v| v.name).collect()
+}
+
+impl Day {
+    pub const fn new<S: Solution + 'static>(number: u32, title: &'static str) -> Self {
+        Day {
+            number,
+            title,
+            params: S::PARAMS,
+            parse: parse_boxed::<S>,
+            variants: variant_names::<S>,
+        }
+    }
+
+    /// Names of the day's other solvers, which `Answers::variant` runs.
+    pub fn variants(&self) -> Vec<&'static str> {
+        (self.variants)()
+    }
+
+    /// The bundled puzzle input, or the sample input from the puzzle text.
+    pub fn input_path(&self, sample: bool) -> PathBuf {
+        let suffix = if sample { "-sample" } else { "" };
+        PathBuf::from(format!("data/day{}{}.txt", self.number, suffix))
+    }
+
+    /// All bundled inputs for this day, such as `day9.txt`, `day9-sample.txt` and `day9-sample2.txt`.
+    pub fn input_files(&self) -> Vec<PathBuf> {
+        let name = format!("day{}.txt", self.number);
+        let prefix = format!("day{}-", self.number);
+        let mut files = fs::read_dir("data")
+            .into_iter()
+            .flatten()
+            .filter_map(|e| e.ok())
+            .map(|e| e.path())
+            .filter(|p| {
+                let file = p.file_name().and_then(|s| s.to_str()).unwrap_or("");
+                file == name || (file.starts_with(&prefix) && file.ends_with(".txt"))
+            })
+            .collect::<Vec<_>>();
+        files.sort();
+        files
+    }
+
+    pub fn parse(&self, input: &str) -> ParseResult<Box<dyn Answers>> {
+        (self.parse)(input)
+    }
+
+    pub fn parse_with(&self, input: &str, params: &Params) -> BoxResult<Box<dyn Answers>> {
+        let mut parsed = self.parse(input)?;
+        self.configure(parsed.as_mut(), params)?;
+        Ok(parsed)
+    }
+
+    fn configure(&self, parsed: &mut dyn Answers, params: &Params) -> BoxResult<()> {
+        if let Some(name) = params.names().find(|n| !self.params.contains(n)) {
+            return Err(format!("day {} has no parameter {}", self.number, name).into());
+        }
+        Ok(parsed.configure(params)?)
+    }
+
+    pub fn parse_reader<R: BufRead>(&self, mut reader: R) -> BoxResult<Box<dyn Answers>> {
+        let mut input = String::new();
+        reader.read_to_string(&mut input)?;
+        Ok(self.parse(&input)?)
+    }
+
+    /// Read and parse an input file, with the parameters from the file next to it.
+    pub fn parse_file(&self, path: &Path) -> BoxResult<Box<dyn Answers>> {
+        let contents = read_input(path).map_err(|e| format!("{}: {}", path.display(), e))?;
+        self.parse_from(path, &contents, &Params::for_input(path)?)
+    }
+
+    /// Parse `input` already read from `path`, so errors can say where they are.
+    pub fn parse_from(
+        &self,
+        path: &Path,
+        input: &str,
+        params: &Params,
+    ) -> BoxResult<Box<dyn Answers>> {
+        let mut parsed = self.parse(input).map_err(|e| e.in_file(path))?;
+        self.configure(parsed.as_mut(), params)
+            .map_err(|e| format!("{}: {}", path.display(), e))?;
+        Ok(parsed)
+    }
+}
+
+/// Every implemented day, in order.
+pub const DAYS: &[Day] = &[
+    Day::new::<days::day1::Elves>(1, "Calorie Counting"),
+    Day::new::<days::day2::Inputs>(2, "Rock Paper Scissors"),
+    Day::new::<days::day3::Inputs>(3, "Rucksack Reorganization"),
+    Day::new::<days::day4::Inputs>(4, "Camp Cleanup"),
+    Day::new::<days::day5::Input>(5, "Supply Stacks"),
+    Day::new::<days::day6::Signal>(6, "Tuning Trouble"),
+    Day::new::<days::day7::Dir>(7, "No Space Left On Device"),
+    Day::new::<days::day8::Grid>(8, "Treetop Tree House"),
+    Day::new::<days::day9::Motions>(9, "Rope Bridge"),
+    Day::new::<days::day10::Program>(10, "Cathode-Ray Tube"),
+    Day::new::<days::day11::Notes>(11, "Monkey in the Middle"),
+    Day::new::<days::day12::Grid>(12, "Hill Climbing Algorithm"),
+    Day::new::<days::day13::Input>(13, "Distress Signal"),
+    Day::new::<days::day14::Cave>(14, "Regolith Reservoir"),
+    Day::new::<days::day15::Input>(15, "Beacon Exclusion Zone"),
+    Day::new::<days::day16::Input>(16, "Proboscidea Volcanium"),
+];
+
+pub fn day(number: u32) -> Option<Day> {
+    DAYS.iter().find(|d| d.number == number).copied()
+}
+
+/// Read a whole input file, or standard input if the path is `-`.
+pub fn read_input(path: &Path) -> io::Result<String> {
+    if path == Path::new("-") {
+        let mut input = String::new();
+        io::stdin().lock().read_to_string(&mut input)?;
+        Ok(input)
+    } else {
+        fs::read_to_string(path)
+    }
+}
+
+/// Open an input to read a line at a time, with `-` meaning standard input.
+pub fn open_input(path: &Path) -> io::Result<Box<dyn BufRead>> {
+    if path == Path::new("-") {
+        Ok(Box::new(io::stdin().lock()))
+    } else {
+        Ok(Box::new(io::BufReader::new(fs::File::open(path)?)))
+    }
+}
+
+pub fn print_answer<T: Display>(part: u32, answer: T) {
+    let answer = answer.to_string();
+    if answer.contains('\n') {
+        println!("Part {}:\n{}", part, answer.trim_end());
+    } else {
+        println!("Part {}: {}", part, answer);
+    }
+}
@@ -0,0 +1,109 @@
+use std::collections::BTreeMap;
+use std::fmt::Display;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+
+use toml::{Table, Value};
+
+use crate::BoxResult;
+
+/// Puzzle constants that differ between inputs, such as the row day 15 scans.
+///
+/// They come from a TOML file next to the input, with the same name but a `.toml` extension,
+/// and from `--param name=value` flags, which win:
+///
+/// ```toml
+/// # data/day15-sample.toml
+/// row = 10
+/// max = 20
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Params {
+    values: BTreeMap<String, String>,
+}
+
+impl Params {
+    pub fn new() -> Self {
+        Params::default()
+    }
+
+    pub fn set(&mut self, name: impl Into<String>, value: impl Into<String>) {
+        self.values.insert(name.into(), value.into());
+    }
+
+    /// Set a parameter from a flag like `row=10`.
+    pub fn set_flag(&mut self, flag: &str) -> Result<(), String> {
+        let (name, value) = flag
+            .split_once('=')
+            .ok_or(format!("expected name=value, not {}", flag))?;
+        self.set(name.trim(), value.trim());
+        Ok(())
+    }
+
+    pub fn parse(contents: &str) -> BoxResult<Self> {
+        let table: Table = contents.parse()?;
+        let mut params = Params::new();
+        for (name, v) in table {
+            let value = match v {
+                Value::String(s) => s,
+                Value::Integer(i) => i.to_string(),
+                Value::Float(f) => f.to_string(),
+                Value::Boolean(b) => b.to_string(),
+                _ => return Err(format!("{} can't be {}", name, v.type_str()).into()),
+            };
+            params.set(name, value);
+        }
+        Ok(params)
+    }
+
+    /// Where the parameters for the input at `path` live.
+    pub fn path_for(input: &Path) -> PathBuf {
+        input.with_extension("toml")
+    }
+
+    /// The parameters for the input at `path`, or none if it doesn't have a file of them.
+    pub fn for_input(path: &Path) -> BoxResult<Self> {
+        let params_path = Params::path_for(path);
+        if path == Path::new("-") || !params_path.exists() {
+            return Ok(Params::new());
+        }
+        let contents = fs::read_to_string(&params_path)?;
+        Params::parse(&contents).map_err(|e| format!("{}: {}", params_path.display(), e).into())
+    }
+
+    /// Add `other`'s values, replacing any already set.
+    pub fn extend(&mut self, other: &Params) {
+        for (name, value) in &other.values {
+            self.set(name, value);
+        }
+    }
+
+    pub fn names(&self) -> impl Iterator<Item = &str> {
+        self.values.keys().map(String::as_str)
+    }
+
+    pub fn get<T: FromStr>(&self, name: &str) -> Result<Option<T>, String>
+    where
+        T::Err: Display,
+    {
+        self.values
+            .get(name)
+            .map(|v| {
+                v.parse()
+                    .map_err(|e| format!("bad {} {:?}: {}", name, v, e))
+            })
+            .transpose()
+    }
+
+    /// Overwrite `value` with parameter `name`, if it's set.
+    pub fn update<T: FromStr>(&self, name: &str, value: &mut T) -> Result<(), String>
+    where
+        T::Err: Display,
+    {
+        if let Some(v) = self.get(name)? {
+            *value = v;
+        }
+        Ok(())
+    }
+}
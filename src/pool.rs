@@ -0,0 +1,48 @@
+//! Running independent jobs on a few threads, so one job panicking doesn't take down the rest.
+
+use std::any::Any;
+use std::panic::{self, AssertUnwindSafe};
+use std::sync::Mutex;
+use std::thread;
+
+/// `f` applied to each job, using up to `threads` threads, with results in the same order as
+/// `jobs`. A job that panics gives the panic's message instead.
+pub fn map<T, R, F>(jobs: Vec<T>, threads: usize, f: F) -> Vec<Result<R, String>>
+where
+    T: Send,
+    R: Send,
+    F: Fn(T) -> R + Sync,
+{
+    let count = jobs.len();
+    let queue = Mutex::new(jobs.into_iter().enumerate());
+    let results = Mutex::new(Vec::with_capacity(count));
+    thread::scope(|s| {
+        for _ in 0..threads.clamp(1, count.max(1)) {
+            s.spawn(|| loop {
+                let Some((i, job)) = queue.lock().unwrap().next() else {
+                    break;
+                };
+                let result = panic::catch_unwind(AssertUnwindSafe(|| f(job)));
+                let result = result.map_err(|e| panic_message(e.as_ref()));
+                results.lock().unwrap().push((i, result));
+            });
+        }
+    });
+    let mut results = results.into_inner().unwrap();
+    results.sort_by_key(|(i, _)| *i);
+    results.into_iter().map(|(_, r)| r).collect()
+}
+
+/// How many threads to use by default: one per core.
+pub fn default_threads() -> usize {
+    thread::available_parallelism().map_or(1, |n| n.get())
+}
+
+/// The message a panic was raised with, if it had one.
+pub fn panic_message(payload: &(dyn Any + Send)) -> String {
+    payload
+        .downcast_ref::<&str>()
+        .map(|s| s.to_string())
+        .or_else(|| payload.downcast_ref::<String>().cloned())
+        .unwrap_or_else(|| "panicked".to_owned())
+}
@@ -0,0 +1,286 @@
+use std::fmt::{self, Write};
+use std::str::FromStr;
+use std::time::Duration;
+
+/// How the runner prints results.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Format {
+    Text,
+    /// One JSON object per line.
+    Json,
+}
+
+impl FromStr for Format {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "text" => Ok(Format::Text),
+            "json" => Ok(Format::Json),
+            _ => Err(format!("no such format {}", s)),
+        }
+    }
+}
+
+/// One answer, and how long it took to compute.
+#[derive(Debug, Clone)]
+pub struct Record {
+    pub day: u32,
+    pub part: u32,
+    pub answer: String,
+    pub elapsed: Duration,
+}
+
+impl Record {
+    pub fn to_json(&self) -> String {
+        format!(
+            r#"{{"day": {}, "part": {}, "answer": {}, "elapsed_ns": {}}}"#,
+            self.day,
+            self.part,
+            json_string(&self.answer),
+            self.elapsed.as_nanos()
+        )
+    }
+}
+
+/// Quote a string for JSON.
+pub fn json_string(s: &str) -> String {
+    let mut out = String::with_capacity(s.len() + 2);
+    out.push('"');
+    for c in s.chars() {
+        match c {
+            '"' => out.push_str("\\\""),
+            '\\' => out.push_str("\\\\"),
+            '\n' => out.push_str("\\n"),
+            '\r' => out.push_str("\\r"),
+            '\t' => out.push_str("\\t"),
+            c if (c as u32) < 0x20 => write!(out, "\\u{:04x}", c as u32).unwrap(),
+            c => out.push(c),
+        }
+    }
+    out.push('"');
+    out
+}
+
+/// A step of solving a day that gets timed separately.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Phase {
+    Parse,
+    Part1,
+    Part2,
+}
+
+impl Phase {
+    pub fn part(part: u32) -> Self {
+        if part == 1 {
+            Phase::Part1
+        } else {
+            Phase::Part2
+        }
+    }
+}
+
+impl fmt::Display for Phase {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(match self {
+            Phase::Parse => "parse",
+            Phase::Part1 => "part1",
+            Phase::Part2 => "part2",
+        })
+    }
+}
+
+/// Summary of repeated timings of one phase.
+#[derive(Debug, Clone)]
+pub struct Bench {
+    pub day: u32,
+    pub phase: Phase,
+    pub runs: usize,
+    pub min: Duration,
+    pub median: Duration,
+    pub max: Duration,
+}
+
+impl Bench {
+    /// Summarise `times`, which must not be empty.
+    pub fn new(day: u32, phase: Phase, mut times: Vec<Duration>) -> Self {
+        times.sort();
+        Bench {
+            day,
+            phase,
+            runs: times.len(),
+            min: times[0],
+            median: times[times.len() / 2],
+            max: times[times.len() - 1],
+        }
+    }
+
+    pub fn to_json(&self) -> String {
+        format!(
+            r#"{{"day": {}, "phase": "{}", "runs": {}, "min_ns": {}, "median_ns": {}, "max_ns": {}}}"#,
+            self.day,
+            self.phase,
+            self.runs,
+            self.min.as_nanos(),
+            self.median.as_nanos(),
+            self.max.as_nanos()
+        )
+    }
+
+    pub const HEADER: &'static str = "day  phase         min      median         max";
+
+    pub fn to_row(&self) -> String {
+        format!(
+            "{:>3}  {:<5}  {:>10}  {:>10}  {:>10}",
+            self.day,
+            self.phase,
+            duration(self.min),
+            duration(self.median),
+            duration(self.max)
+        )
+    }
+}
+
+/// A duration to a few significant figures, like `1.2ms`.
+pub fn duration(d: Duration) -> String {
+    format!("{:.1?}", d)
+}
+
+/// One timed solve of a day: its answers, and how long parsing and each part took.
+#[derive(Debug, Clone, Default)]
+pub struct Run {
+    pub answers: Vec<(u32, String)>,
+    pub times: Vec<(Phase, Duration)>,
+}
+
+impl Run {
+    pub fn time(&self, phase: Phase) -> Option<Duration> {
+        self.times
+            .iter()
+            .find(|(p, _)| *p == phase)
+            .map(|(_, t)| *t)
+    }
+
+    /// Describe this run line by line, noting what changed since `prev`.
+    pub fn diff(&self, prev: Option<&Run>) -> Vec<String> {
+        let mut lines = Vec::new();
+        for (part, answer) in &self.answers {
+            let before = prev.and_then(|r| r.answers.iter().find(|(p, _)| p == part));
+            let note = match before {
+                None => String::new(),
+                Some((_, b)) if b == answer => " (same)".to_owned(),
+                Some(_) if answer.contains('\n') => " (changed)".to_owned(),
+                Some((_, b)) => format!(" (was {})", b),
+            };
+            if answer.contains('\n') {
+                lines.push(format!("Part {}{}:", part, note));
+                lines.extend(answer.trim_end().lines().map(str::to_owned));
+            } else {
+                lines.push(format!("Part {}: {}{}", part, answer, note));
+            }
+        }
+        let times = self
+            .times
+            .iter()
+            .map(|&(phase, t)| {
+                let change = match prev.and_then(|r| r.time(phase)) {
+                    Some(b) if !b.is_zero() => {
+                        let percent = (t.as_secs_f64() / b.as_secs_f64() - 1.0) * 100.0;
+                        format!(" ({:+.0}%)", percent)
+                    }
+                    _ => String::new(),
+                };
+                format!("{} {}{}", phase, duration(t), change)
+            })
+            .collect::<Vec<_>>();
+        lines.push(format!("Time: {}", times.join(", ")));
+        lines
+    }
+}
+
+/// How solving one day went, for the summary of a whole run.
+#[derive(Debug, Clone)]
+pub struct Outcome {
+    pub day: u32,
+    pub title: &'static str,
+    pub result: Result<Run, String>,
+}
+
+/// A table of every day's answers and times, followed by anything too long for it: answers
+/// that take several lines, and why days failed.
+pub fn summary(outcomes: &[Outcome], wall: Duration) -> Vec<String> {
+    const HEADER: [&str; 8] = [
+        "day", "title", "part 1", "part 2", "parse", "solve 1", "solve 2", "total",
+    ];
+    // Days, times and the total line up on the right; titles and answers on the left.
+    const RIGHT: [bool; 8] = [true, false, false, false, true, true, true, true];
+
+    let mut rows = vec![HEADER.map(str::to_owned).to_vec()];
+    let mut notes = Vec::new();
+    for outcome in outcomes {
+        let mut row = [outcome.day.to_string(), outcome.title.to_owned()]
+            .into_iter()
+            .chain(std::iter::repeat_n("-".to_owned(), 6))
+            .collect::<Vec<_>>();
+        match &outcome.result {
+            Ok(run) => {
+                for (part, answer) in &run.answers {
+                    row[1 + *part as usize] = if answer.contains('\n') {
+                        notes.push(format!("Day {} part {}:", outcome.day, part));
+                        notes.extend(answer.trim_end().lines().map(str::to_owned));
+                        format!("({} lines)", answer.trim_end().lines().count())
+                    } else {
+                        answer.clone()
+                    };
+                }
+                for &(phase, t) in &run.times {
+                    let column = match phase {
+                        Phase::Parse => 4,
+                        Phase::Part1 => 5,
+                        Phase::Part2 => 6,
+                    };
+                    row[column] = duration(t);
+                }
+                row[7] = duration(run.times.iter().map(|(_, t)| *t).sum());
+            }
+            Err(e) => {
+                row[2] = "error".to_owned();
+                notes.push(format!("Day {} failed: {}", outcome.day, e));
+            }
+        }
+        rows.push(row);
+    }
+
+    let widths: Vec<usize> = (0..HEADER.len())
+        .map(|i| rows.iter().map(|r| r[i].chars().count()).max().unwrap())
+        .collect();
+    let mut lines = Vec::new();
+    for (n, row) in rows.iter().enumerate() {
+        let cells = row.iter().enumerate().map(|(i, cell)| {
+            if RIGHT[i] {
+                format!("{:>1$}", cell, widths[i])
+            } else {
+                format!("{:<1$}", cell, widths[i])
+            }
+        });
+        lines.push(cells.collect::<Vec<_>>().join("  ").trim_end().to_owned());
+        if n == 0 {
+            let rule = widths.iter().map(|&w| "-".repeat(w));
+            lines.push(rule.collect::<Vec<_>>().join("  "));
+        }
+    }
+
+    let solved = outcomes.iter().filter(|o| o.result.is_ok()).count();
+    lines.push(String::new());
+    lines.push(format!(
+        "{} of {} days solved in {}",
+        solved,
+        outcomes.len(),
+        duration(wall)
+    ));
+    if !notes.is_empty() {
+        lines.push(String::new());
+        lines.extend(notes);
+    }
+    lines
+}
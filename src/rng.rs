@@ -0,0 +1,68 @@
+//! A small seeded random number generator, so generated inputs are the same on every machine.
+
+use std::ops::RangeInclusive;
+
+/// SplitMix64: fast, tiny, and good enough for making up puzzle inputs. Not for anything that
+/// needs to be unpredictable.
+#[derive(Debug, Clone)]
+pub struct Rng(u64);
+
+/// Integers `Rng::range` can produce.
+pub trait Int: Copy {
+    fn to_i128(self) -> i128;
+    fn from_i128(i: i128) -> Self;
+}
+
+macro_rules! impl_int {
+    ($($t:ty),*) => {
+        $(impl Int for $t {
+            fn to_i128(self) -> i128 {
+                self as i128
+            }
+
+            fn from_i128(i: i128) -> Self {
+                i as $t
+            }
+        })*
+    };
+}
+
+impl_int!(i32, i64, u8, u32, u64, usize);
+
+impl Rng {
+    pub fn new(seed: u64) -> Self {
+        Rng(seed)
+    }
+
+    pub fn next_u64(&mut self) -> u64 {
+        self.0 = self.0.wrapping_add(0x9e37_79b9_7f4a_7c15);
+        let mut z = self.0;
+        z = (z ^ (z >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+        z ^ (z >> 31)
+    }
+
+    /// A number in `range`, which must not be empty.
+    pub fn range<T: Int>(&mut self, range: RangeInclusive<T>) -> T {
+        let (lo, hi) = (range.start().to_i128(), range.end().to_i128());
+        assert!(lo <= hi, "empty range");
+        let span = (hi - lo + 1) as u128;
+        T::from_i128(lo + (self.next_u64() as u128 % span) as i128)
+    }
+
+    /// True with probability `p`.
+    pub fn chance(&mut self, p: f64) -> bool {
+        ((self.next_u64() >> 11) as f64) < p * (1u64 << 53) as f64
+    }
+
+    /// A random element of `items`, which must not be empty.
+    pub fn pick<'a, T>(&mut self, items: &'a [T]) -> &'a T {
+        &items[self.range(0..=items.len() - 1)]
+    }
+
+    pub fn shuffle<T>(&mut self, items: &mut [T]) {
+        for i in (1..items.len()).rev() {
+            items.swap(i, self.range(0..=i));
+        }
+    }
+}
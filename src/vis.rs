@@ -0,0 +1,185 @@
+//! Watching simulations step by step in the terminal.
+//!
+//! A day that simulates something calls `Viewer::show` once per step, with a closure that draws
+//! the current state as a `Frame`. Viewers only call it for frames they'll use, so solving
+//! normally with `Hidden` costs next to nothing.
+
+use std::fmt;
+use std::io::{self, BufRead, Write};
+use std::thread;
+use std::time::Duration;
+
+use crate::geometry::{Bounds, Point};
+use crate::grid::Grid;
+
+/// One picture of a simulation's state.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Frame {
+    /// What just happened, like "move 3 from 1 to 2".
+    pub caption: String,
+    pub rows: Vec<String>,
+}
+
+impl Frame {
+    pub fn new(caption: impl Into<String>) -> Self {
+        Frame {
+            caption: caption.into(),
+            rows: Vec::new(),
+        }
+    }
+
+    /// Add text, one row per line.
+    pub fn text(mut self, text: &str) -> Self {
+        self.rows.extend(text.lines().map(str::to_owned));
+        self
+    }
+
+    /// Add a grid, one character per cell.
+    pub fn grid<T>(self, grid: &Grid<T>, f: impl Fn(&T) -> char) -> Self {
+        self.text(&grid.render(f))
+    }
+
+    /// Add the points in `bounds`, one character each, with y increasing down the screen.
+    pub fn area(mut self, bounds: Bounds, f: impl Fn(Point) -> char) -> Self {
+        for y in bounds.min.y..=bounds.max.y {
+            let row = (bounds.min.x..=bounds.max.x).map(|x| f(Point::new(x, y)));
+            self.rows.push(row.collect());
+        }
+        self
+    }
+}
+
+impl fmt::Display for Frame {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        writeln!(f, "{}", self.caption)?;
+        for row in &self.rows {
+            writeln!(f, "{}", row)?;
+        }
+        Ok(())
+    }
+}
+
+/// Something that watches a simulation.
+pub trait Viewer {
+    /// Called once per simulation step. `draw` renders the state after the step.
+    fn show(&mut self, draw: &mut dyn FnMut() -> Frame);
+}
+
+/// Shows nothing, for solving without watching.
+pub struct Hidden;
+
+impl Viewer for Hidden {
+    fn show(&mut self, _draw: &mut dyn FnMut() -> Frame) {}
+}
+
+/// Keeps every `every`th frame, so tests and exporters can look at them afterwards.
+pub struct Recorder {
+    pub frames: Vec<Frame>,
+    every: usize,
+    steps: usize,
+}
+
+impl Recorder {
+    pub fn new(every: usize) -> Self {
+        Recorder {
+            frames: Vec::new(),
+            every: every.max(1),
+            steps: 0,
+        }
+    }
+}
+
+impl Viewer for Recorder {
+    fn show(&mut self, draw: &mut dyn FnMut() -> Frame) {
+        if self.steps.is_multiple_of(self.every) {
+            self.frames.push(draw());
+        }
+        self.steps += 1;
+    }
+}
+
+/// How `Player` moves from one frame to the next.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Mode {
+    /// Wait for a command after each frame.
+    Step,
+    /// Show frames on a timer.
+    Play,
+    /// Stop drawing and let the simulation finish.
+    Done,
+}
+
+/// Plays frames in the terminal, redrawing over the last one.
+///
+/// In step mode, each frame waits for a line on standard input: nothing for the next frame, a
+/// number to skip that many, `p` to play from here, or `q` to stop watching.
+pub struct Player<W: Write> {
+    out: W,
+    mode: Mode,
+    /// Frames per second when playing.
+    fps: f64,
+    /// Only every `every`th step is drawn, for simulations with more steps than anyone wants to
+    /// watch.
+    every: usize,
+    steps: usize,
+    skip: usize,
+}
+
+impl<W: Write> Player<W> {
+    pub fn new(out: W, mode: Mode, fps: f64, every: usize) -> Self {
+        Player {
+            out,
+            mode,
+            fps,
+            every: every.max(1),
+            steps: 0,
+            skip: 0,
+        }
+    }
+
+    fn draw(&mut self, frame: &Frame) -> io::Result<()> {
+        // Clear the screen and move to the top left.
+        write!(self.out, "\x1b[2J\x1b[H{}", frame)?;
+        write!(self.out, "step {}", self.steps)?;
+        if self.mode == Mode::Step {
+            write!(self.out, "  [enter: next, N: skip N, p: play, q: quit] ")?;
+        } else {
+            writeln!(self.out)?;
+        }
+        self.out.flush()
+    }
+
+    fn wait(&mut self) -> io::Result<()> {
+        match self.mode {
+            Mode::Step => {
+                let mut line = String::new();
+                io::stdin().lock().read_line(&mut line)?;
+                match line.trim() {
+                    "q" => self.mode = Mode::Done,
+                    "p" => self.mode = Mode::Play,
+                    n => self.skip = n.parse().unwrap_or(0),
+                }
+            }
+            Mode::Play => thread::sleep(Duration::from_secs_f64(1.0 / self.fps)),
+            Mode::Done => (),
+        }
+        Ok(())
+    }
+}
+
+impl<W: Write> Viewer for Player<W> {
+    fn show(&mut self, draw: &mut dyn FnMut() -> Frame) {
+        self.steps += 1;
+        if self.mode == Mode::Done || !(self.steps - 1).is_multiple_of(self.every) {
+            return;
+        }
+        if self.skip > 0 {
+            self.skip -= 1;
+            return;
+        }
+        // A closed terminal just means nobody is watching any more.
+        if self.draw(&draw()).and_then(|_| self.wait()).is_err() {
+            self.mode = Mode::Done;
+        }
+    }
+}
@@ -0,0 +1,46 @@
+use advent2022::answers::Manifest;
+
+#[test]
+fn parse_manifest() {
+    let manifest = Manifest::parse(
+        r#"
+[day5."day5-sample.txt"]
+part1 = "CMZ"
+part2 = "MCD"
+
+[day9."day9.txt"]
+part1 = 6337
+"#,
+    )
+    .unwrap();
+
+    let day5 = manifest.get(5, "day5-sample.txt").unwrap();
+    assert_eq!(day5.part(1), Some("CMZ"));
+    assert_eq!(day5.part(2), Some("MCD"));
+
+    let day9 = manifest.get(9, "day9.txt").unwrap();
+    assert_eq!(day9.part(1), Some("6337"));
+    assert_eq!(day9.part(2), None);
+
+    assert!(manifest.get(9, "day9-sample.txt").is_none());
+
+    let entries: Vec<_> = manifest
+        .entries()
+        .into_iter()
+        .map(|(day, file, _)| (day, file))
+        .collect();
+    assert_eq!(entries, [(5, "day5-sample.txt"), (9, "day9.txt")]);
+}
+
+#[test]
+fn parse_manifest_errors() {
+    assert!(Manifest::parse("[nine.\"day9.txt\"]\npart1 = 1\n").is_err());
+    assert!(Manifest::parse("[day9.\"day9.txt\"]\npart3 = 1\n").is_err());
+    assert!(Manifest::parse("[day9.\"day9.txt\"]\npart1 = 1.5\n").is_err());
+}
+
+#[test]
+fn bundled_manifest_loads() {
+    let manifest = Manifest::load(Manifest::PATH).unwrap();
+    assert!(manifest.get(1, "day1.txt").is_some());
+}
@@ -0,0 +1,153 @@
+use std::io::{self, BufRead, Read};
+
+use advent2022::days::day1::{self, TopK};
+use advent2022::{Params, ParseError, Solution};
+
+const SAMPLE: &str = "1000\n2000\n3000\n\n4000\n\n5000\n6000\n\n7000\n8000\n9000\n\n10000\n";
+
+#[test]
+fn top_k() {
+    let top = day1::top_k(SAMPLE.as_bytes(), 3).unwrap();
+    assert_eq!(top.largest(), [(3, 24000), (2, 11000), (4, 10000)]);
+    assert_eq!(top.total(), 45000);
+
+    // Asking for more elves than there are gives them all.
+    let top = day1::top_k(SAMPLE.as_bytes(), 10).unwrap();
+    assert_eq!(top.largest().len(), 5);
+    assert_eq!(day1::top_k(SAMPLE.as_bytes(), 0).unwrap().total(), 0);
+    let top = day1::top_k(SAMPLE.as_bytes(), usize::MAX).unwrap();
+    assert_eq!((top.largest().len(), top.total()), (5, 55000));
+
+    let mut top = TopK::new(2);
+    for (elf, sum) in [5, 7, 7, 3, 7].into_iter().enumerate() {
+        top.push(elf, sum);
+    }
+    assert_eq!(top.largest(), [(1, 7), (2, 7)]);
+}
+
+#[test]
+fn part2_top_param() {
+    let mut elves = day1::Elves::parse(SAMPLE).unwrap();
+    let mut params = Params::new();
+    params.set("top", "2");
+    elves.configure(&params).unwrap();
+    assert_eq!(elves.part2(), 35000);
+}
+
+#[test]
+fn big_totals() {
+    // Each total fits in an i64, but not in an i32, and the top two together fit in neither.
+    let big = i64::MAX / 2 + 1;
+    let input = format!("{}\n\n{}\n\n3000000000\n", big, big);
+    let elves = day1::Elves::parse(&input).unwrap();
+    assert_eq!(elves.part1(), big);
+    assert_eq!(elves.part2(), 2 * big as i128 + 3_000_000_000);
+}
+
+#[test]
+fn errors_have_line_numbers() {
+    let err = day1::top_k("1\n2\n\n3x\n".as_bytes(), 3).unwrap_err();
+    let err = err.downcast::<ParseError>().unwrap();
+    assert_eq!((err.line, err.column, err.text.as_str()), (4, 1, "3x"));
+
+    let err = day1::top_k("\n\n".as_bytes(), 3).unwrap_err();
+    assert_eq!(err.to_string(), "no elves found");
+}
+
+/// A reader of `elves` elves with 1000 snacks each, made up as it's read.
+struct Endless {
+    elves: usize,
+    elf: usize,
+    line: Vec<u8>,
+    at: usize,
+}
+
+impl Read for Endless {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        let n = self.fill_buf()?.read(buf)?;
+        self.consume(n);
+        Ok(n)
+    }
+}
+
+impl BufRead for Endless {
+    fn fill_buf(&mut self) -> io::Result<&[u8]> {
+        if self.at == self.line.len() && self.elf < self.elves {
+            self.line = format!("{}\n", self.elf % 1000).repeat(1000).into_bytes();
+            self.line.push(b'\n');
+            self.elf += 1;
+            self.at = 0;
+        }
+        Ok(&self.line[self.at..])
+    }
+
+    fn consume(&mut self, n: usize) {
+        self.at += n;
+    }
+}
+
+#[test]
+fn streams() {
+    // About 8MB of input, which is never all in memory at once.
+    let reader = Endless {
+        elves: 2000,
+        elf: 0,
+        line: Vec::new(),
+        at: 0,
+    };
+    let top = day1::top_k(reader, 3).unwrap();
+    assert_eq!(
+        top.largest(),
+        [(999, 999_000), (1999, 999_000), (998, 998_000)]
+    );
+}
+
+#[test]
+fn stats() {
+    // The sample, with the second and third elves split by two blank lines instead of one.
+    let input = SAMPLE.replace("4000\n\n", "4000\n\n\n");
+    let elves = day1::Elves::parse(&input).unwrap();
+    let stats = elves.stats(2, 4);
+    assert_eq!(stats.elves, 5);
+    assert_eq!(stats.empty, [7]);
+    assert_eq!(stats.top, [(3, 24000), (2, 11000)]);
+    assert_eq!(stats.total, 55000);
+    assert_eq!((stats.mean, stats.median), (11000.0, 10000.0));
+    assert_eq!(
+        stats.percentiles,
+        [
+            (10, 4000),
+            (25, 6000),
+            (75, 11000),
+            (90, 24000),
+            (99, 24000)
+        ]
+    );
+    let counts: Vec<_> = stats.histogram.iter().map(|b| b.count).collect();
+    assert_eq!(counts, [2, 2, 0, 1]);
+    assert_eq!(
+        (stats.histogram[0].min, stats.histogram[3].max),
+        (4000, 24000)
+    );
+
+    // Blank lines at either end of a group don't make empty ones, except before the first.
+    let elves = day1::Elves::parse("\n1\n\n2\n\n\n").unwrap();
+    assert_eq!(elves.stats(1, 1).empty, [1]);
+    assert_eq!(day1::Elves::parse(SAMPLE).unwrap().stats(1, 1).empty, []);
+
+    // An even number of elves has the median halfway between the middle two.
+    let stats = day1::Elves::parse("1\n\n2\n\n4\n\n9\n")
+        .unwrap()
+        .stats(1, 100);
+    assert_eq!(stats.median, 3.0);
+    assert_eq!(stats.histogram.len(), 9);
+}
+
+#[test]
+fn stats_json() {
+    let elves = day1::Elves::parse("5\n\n\n\n7\n").unwrap();
+    assert_eq!(
+        elves.stats(1, 2).to_json(),
+        r#"{"elves": 2, "empty_groups": [3, 4], "total": 12, "mean": 6, "median": 6, "percentiles": {"p10": 5, "p25": 5, "p75": 7, "p90": 7, "p99": 7}, "top": [{"elf": 2, "calories": 7}], "histogram": [{"min": 5, "max": 6, "count": 1}, {"min": 7, "max": 7, "count": 1}]}"#
+    );
+}
@@ -0,0 +1,336 @@
+use std::io::Write;
+use std::process::{Command, Output, Stdio};
+
+fn aoc(args: &[&str], stdin: &str) -> Output {
+    let mut child = Command::new(env!("CARGO_BIN_EXE_aoc"))
+        .args(args)
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()
+        .unwrap();
+    child
+        .stdin
+        .take()
+        .unwrap()
+        .write_all(stdin.as_bytes())
+        .unwrap();
+    child.wait_with_output().unwrap()
+}
+
+#[test]
+fn run_from_stdin() {
+    let out = aoc(
+        &["run", "6", "--input", "-"],
+        "mjqjpqmgbljsphdztnvjfqwrcgsmlb\n",
+    );
+    assert!(out.status.success());
+    assert_eq!(
+        String::from_utf8(out.stdout).unwrap(),
+        "Part 1: 7\nPart 2: 19\n"
+    );
+}
+
+#[test]
+fn parse_error_exit_code() {
+    let out = aoc(&["run", "10", "--input", "-"], "noop\nfoo\n");
+    assert_eq!(out.status.code(), Some(1));
+    let err = String::from_utf8(out.stderr).unwrap();
+    assert!(
+        err.starts_with("error: -:2:1: unknown instruction"),
+        "{}",
+        err
+    );
+}
+
+#[test]
+fn json_format() {
+    let out = aoc(
+        &["run", "6", "--input", "-", "--format", "json"],
+        "mjqjpqmgbljsphdztnvjfqwrcgsmlb\n",
+    );
+    assert!(out.status.success());
+    let stdout = String::from_utf8(out.stdout).unwrap();
+    let lines: Vec<&str> = stdout.lines().collect();
+    assert_eq!(lines.len(), 2);
+    assert!(
+        lines[0].starts_with(r#"{"day": 6, "part": 1, "answer": "7", "elapsed_ns": "#),
+        "{}",
+        lines[0]
+    );
+    assert!(
+        lines[1].starts_with(r#"{"day": 6, "part": 2, "answer": "19", "elapsed_ns": "#),
+        "{}",
+        lines[1]
+    );
+}
+
+#[test]
+fn json_escapes_multiline_answers() {
+    let out = aoc(
+        &["run", "10", "--sample", "--part", "2", "--format", "json"],
+        "",
+    );
+    assert!(out.status.success());
+    let stdout = String::from_utf8(out.stdout).unwrap();
+    assert_eq!(stdout.lines().count(), 1);
+    assert!(
+        stdout.contains(r###""answer": "##..##..##..##..##..##..##..##..##..##..\n###"###),
+        "{}",
+        stdout
+    );
+}
+
+#[test]
+fn bench() {
+    let out = aoc(
+        &[
+            "bench", "6", "--input", "-", "--runs", "3", "--format", "json",
+        ],
+        "mjqjpqmgbljsphdztnvjfqwrcgsmlb\n",
+    );
+    assert!(out.status.success());
+    let stdout = String::from_utf8(out.stdout).unwrap();
+    let lines: Vec<&str> = stdout.lines().collect();
+    assert_eq!(lines.len(), 3);
+    for (line, phase) in lines.iter().zip(["parse", "part1", "part2"]) {
+        let start = format!(r#"{{"day": 6, "phase": "{}", "runs": 3, "min_ns": "#, phase);
+        assert!(line.starts_with(&start), "{}", line);
+    }
+}
+
+#[test]
+fn params() {
+    let run = |extra: &[&str]| {
+        let mut args = vec!["run", "15", "--sample", "--part", "1"];
+        args.extend(extra);
+        aoc(&args, "")
+    };
+
+    // The sample's own row comes from data/day15-sample.toml.
+    let out = run(&[]);
+    assert_eq!(String::from_utf8(out.stdout).unwrap(), "Part 1: 26\n");
+
+    let out = run(&["--param", "row=9"]);
+    assert_eq!(String::from_utf8(out.stdout).unwrap(), "Part 1: 25\n");
+
+    let out = run(&["--param", "rows=9"]);
+    assert_eq!(out.status.code(), Some(1));
+    let err = String::from_utf8(out.stderr).unwrap();
+    assert!(err.contains("day 15 has no parameter rows"), "{}", err);
+
+    let out = run(&["--param", "row=x"]);
+    assert_eq!(out.status.code(), Some(1));
+}
+
+#[test]
+fn short_signal() {
+    let run = |extra: &[&str]| {
+        let mut args = vec!["run", "6", "--input", "-", "--part", "2"];
+        args.extend(extra);
+        aoc(&args, "abcdefgh\n")
+    };
+    // Too short for the usual markers, but fine for smaller ones.
+    let out = run(&[]);
+    assert_eq!(out.status.code(), Some(1));
+    let err = String::from_utf8(out.stderr).unwrap();
+    assert!(
+        err.contains("no marker of 14 different characters found"),
+        "{}",
+        err
+    );
+
+    let out = run(&["--param", "part2_window=5"]);
+    assert_eq!(String::from_utf8(out.stdout).unwrap(), "Part 2: 5\n");
+}
+
+#[test]
+fn crosscheck() {
+    let out = aoc(&["crosscheck", "16", "--sample"], "");
+    assert!(out.status.success());
+    let stdout = String::from_utf8(out.stdout).unwrap();
+    assert!(stdout.starts_with("data/day16-sample.txt part 1\n  main           1651"));
+    assert!(stdout.contains("\n  bitmask        1707 "));
+    assert!(!stdout.contains("DISAGREES"));
+
+    let out = aoc(&["crosscheck", "14", "--gen", "2", "--seed", "5"], "");
+    assert!(out.status.success());
+    assert!(String::from_utf8(out.stdout)
+        .unwrap()
+        .starts_with("seed 5 size 20 part 1\n"));
+
+    let out = aoc(&["crosscheck", "1"], "");
+    assert_eq!(out.status.code(), Some(1));
+    assert_eq!(
+        String::from_utf8(out.stderr).unwrap(),
+        "error: day 1 has only one solver\n"
+    );
+}
+
+#[test]
+fn vis() {
+    let out = aoc(
+        &["vis", "5", "--sample", "--part", "1", "--step"],
+        "\n\n2\n",
+    );
+    assert!(out.status.success());
+    let stdout = String::from_utf8(out.stdout).unwrap();
+    assert!(stdout.starts_with("\x1b[2J\x1b[Hmove 1 from 2 to 1\n[D]\n"));
+    assert!(stdout.ends_with("Part 1: CMZ\n"));
+
+    let out = aoc(&["vis", "1", "--sample"], "");
+    assert_eq!(out.status.code(), Some(1));
+}
+
+#[test]
+fn image() {
+    let dir = std::env::temp_dir().join(format!("aoc-image-{}", std::process::id()));
+    let out = aoc(
+        &[
+            "image",
+            "14",
+            "--sample",
+            "--out",
+            dir.to_str().unwrap(),
+            "--frames",
+            "--every",
+            "10",
+        ],
+        "",
+    );
+    assert!(out.status.success());
+    let stdout = String::from_utf8(out.stdout).unwrap();
+    assert!(stdout.contains("3 frames for part 1"));
+    assert!(dir.join("day14-part2.png").exists());
+    assert!(dir.join("day14-part1-00020.png").exists());
+    std::fs::remove_dir_all(&dir).unwrap();
+
+    let out = aoc(
+        &["image", "1", "--sample", "--out", dir.to_str().unwrap()],
+        "",
+    );
+    assert_eq!(out.status.code(), Some(1));
+    let _ = std::fs::remove_dir_all(&dir);
+}
+
+#[test]
+fn watch() {
+    use std::io::{BufRead, BufReader};
+
+    let dir = std::env::temp_dir().join(format!("aoc-watch-{}", std::process::id()));
+    std::fs::create_dir_all(&dir).unwrap();
+    let input = dir.join("day6.txt");
+    std::fs::write(&input, "mjqjpqmgbljsphdztnvjfqwrcgsmlb\n").unwrap();
+
+    let mut child = Command::new(env!("CARGO_BIN_EXE_aoc"))
+        .args(["watch", "6", "--part", "1", "--interval", "20"])
+        .arg("--input")
+        .arg(&input)
+        .stdout(Stdio::piped())
+        .spawn()
+        .unwrap();
+    let mut lines = BufReader::new(child.stdout.take().unwrap()).lines();
+    let mut next_answer = || {
+        lines
+            .by_ref()
+            .map(|l| l.unwrap())
+            .find(|l| l.starts_with("Part"))
+            .unwrap()
+    };
+
+    assert_eq!(next_answer(), "Part 1: 7");
+    std::fs::write(&input, "bvwbjplbgvbhsrlpgdmjqwftvncz\n").unwrap();
+    assert_eq!(next_answer(), "Part 1: 5 (was 7)");
+    std::fs::write(dir.join("day6.toml"), "part1_window = 14\n").unwrap();
+    assert_eq!(next_answer(), "Part 1: 23 (was 5)");
+
+    child.kill().unwrap();
+    child.wait().unwrap();
+    std::fs::remove_dir_all(&dir).unwrap();
+}
+
+#[test]
+fn run_all_summary() {
+    let out = aoc(
+        &["run", "--all", "--sample", "--part", "1", "--jobs", "3"],
+        "",
+    );
+    assert!(out.status.success());
+    let stdout = String::from_utf8(out.stdout).unwrap();
+    let lines: Vec<&str> = stdout.lines().collect();
+    assert!(lines[0].starts_with("day  title"));
+    assert!(lines[2].starts_with("  1  Calorie Counting         24000   -"));
+    assert!(lines[15].starts_with(" 14  Regolith Reservoir       24      -"));
+    assert!(stdout.contains("\n16 of 16 days solved in "));
+}
+
+#[test]
+fn calories() {
+    let out = aoc(
+        &["calories", "--top", "2", "--input", "-"],
+        "1\n2\n\n5\n\n4\n",
+    );
+    assert!(out.status.success());
+    assert_eq!(
+        String::from_utf8(out.stdout).unwrap(),
+        "  1. elf 2      5\n  2. elf 3      4\nTotal: 9\n"
+    );
+
+    let out = aoc(&["calories", "--stats", "--input", "-"], "1\n\n\n2\n");
+    assert!(out.status.success());
+    let stdout = String::from_utf8(out.stdout).unwrap();
+    assert!(
+        stdout.contains("\nempty                1 (line 3)\n"),
+        "{}",
+        stdout
+    );
+    assert!(
+        stdout.contains("\n   1  2                2\n"),
+        "{}",
+        stdout
+    );
+
+    let out = aoc(&["calories", "--input", "-"], "1\nx\n");
+    let err = String::from_utf8(out.stderr).unwrap();
+    assert!(err.starts_with("error: -:2:1: can't parse"), "{}", err);
+}
+
+#[test]
+fn guide() {
+    let out = aoc(&["guide", "--input", "-"], "A Y\nB X\nC Z\n");
+    assert!(out.status.success());
+    let stdout = String::from_utf8(out.stdout).unwrap();
+    assert!(stdout.starts_with("Best possible: 24\nWorst possible: 6\n"));
+    assert!(stdout.contains("\npart2    lose      draw      win            12\n"));
+    assert!(
+        stdout.ends_with("Most likely meant: part1 with X=scissors, Y=paper, Z=rock, scoring 24\n")
+    );
+}
+
+#[test]
+fn verify_stale_answers() {
+    let dir = std::env::temp_dir().join(format!("aoc-verify-{}", std::process::id()));
+    std::fs::create_dir_all(&dir).unwrap();
+    let manifest = dir.join("answers.toml");
+    let answers =
+        "[day1.\"day1-sample.txt\"]\npart1 = 24000\n\n[day1.\"day1-old.txt\"]\npart2 = 1\n";
+    std::fs::write(&manifest, answers).unwrap();
+
+    let out = aoc(
+        &["verify", "1", "--manifest", manifest.to_str().unwrap()],
+        "",
+    );
+    std::fs::remove_dir_all(&dir).unwrap();
+    assert_eq!(out.status.code(), Some(1));
+    let stdout = String::from_utf8(out.stdout).unwrap();
+    assert!(
+        stdout.contains("day1   day1-sample.txt    part1  pass\n"),
+        "{}",
+        stdout
+    );
+    assert!(
+        stdout.contains("day1   day1-old.txt       part2  FAIL: no such input file\n"),
+        "{}",
+        stdout
+    );
+}
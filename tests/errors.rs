@@ -0,0 +1,124 @@
+use advent2022::days::*;
+use advent2022::{ParseError, Solution};
+
+fn error<S: Solution>(input: &str) -> ParseError {
+    match S::parse(input) {
+        Ok(_) => panic!("expected an error parsing {:?}", input),
+        Err(e) => e,
+    }
+}
+
+#[test]
+fn error_position() {
+    let input = "abc\ndef ghi\njkl";
+    let e = ParseError::at(input, &input[8..11], "oops");
+    assert_eq!((e.line, e.column), (2, 5));
+    assert_eq!(e.text, "def ghi");
+    assert_eq!(e.to_string(), "2:5: oops\n  def ghi\n      ^");
+
+    let e = ParseError::at_end(input, "oops");
+    assert_eq!((e.line, e.column), (3, 4));
+}
+
+#[test]
+fn day_errors() {
+    let e = error::<day2::Inputs>("A Y\nB Q\n");
+    assert_eq!(
+        (e.line, e.column, e.message.as_str()),
+        (2, 3, "expected X, Y or Z")
+    );
+    let e = error::<day2::Inputs>("A Y\nAB X\n");
+    assert_eq!(
+        (e.line, e.column, e.message.as_str()),
+        (2, 1, "expected A, B or C")
+    );
+    let e = error::<day2::Inputs>("A Y\nB X Z\n");
+    assert_eq!((e.line, e.message.as_str()), (2, "expected two columns"));
+    let e = error::<day2::Inputs>("A Y\n\nB X\n");
+    assert_eq!((e.line, e.message.as_str()), (2, "expected two columns"));
+
+    let e = error::<day4::Inputs>("2-4,6-8\n2-3,4\n");
+    assert_eq!((e.line, e.column), (2, 5));
+
+    let e = error::<day5::Input>("    [D]\n[N] [C]\n 1   2\n\nmove 1 from 3 to 1\n");
+    assert_eq!((e.line, e.column), (5, 13));
+    assert_eq!(e.message, "no stack 3, expected 1 to 2");
+
+    let e = error::<day7::Dir>("$ cd /\n$ ls\ndir a\n$ cd b\n$ ls\n1 x\n");
+    assert_eq!(e.line, 6);
+
+    let e = error::<day10::Program>("noop\naddx 1x\n");
+    assert_eq!((e.line, e.column), (2, 6));
+
+    let e = error::<day13::Input>("[1,[2]\n[3]\n");
+    assert_eq!((e.line, e.column), (1, 7));
+    assert_eq!(e.message, "expected ',' or ']'");
+
+    let e = error::<day14::Cave>("498,4 -> 498,6 -> 496,7\n");
+    assert_eq!((e.line, e.column), (1, 19));
+}
+
+/// Inputs the fuzzer found, or would have: each used to panic or hang.
+#[test]
+fn hostile_inputs() {
+    let e = error::<day1::Elves>("9223372036854775807\n1\n");
+    assert_eq!((e.line, e.message.as_str()), (2, "too many calories"));
+
+    // Which markers there are depends on the windows, so only `configure` can reject this.
+    let signal = day6::Signal::parse("abcabcabcabcd\n").unwrap();
+    assert_eq!((signal.part1(), signal.part2()), (13, 0));
+
+    let e = error::<day7::Dir>("$ cd /\n$ ls\n18446744073709551615 a\n1 b\n");
+    assert_eq!(e.line, 4);
+
+    let deep = "[".repeat(100_000);
+    let e = error::<day13::Input>(&format!("{}\n[1]\n", deep));
+    assert_eq!(e.message, "lists are nested too deeply");
+
+    let e = error::<day14::Cave>("498,4 -> 498,999999\n");
+    assert_eq!((e.line, e.column), (1, 10));
+
+    // Rock far to the side is out of the sand's reach, so it's skipped, not walked along. This
+    // floor catches all the sand, so part 1 has to stop when the source fills up.
+    let cave = day14::Cave::parse("-2000000000,9 -> 2000000000,9\n").unwrap();
+    assert_eq!(cave.part1(), 81);
+}
+
+/// Inputs that are well formed but have no answers, which are reported rather than panicking.
+#[test]
+fn unsolvable_inputs() {
+    let e = error::<day3::Inputs>("vJrwpWtwJgWrhcsFMMfFFhFp\nab\n");
+    assert_eq!(
+        (e.line, e.column, e.message.as_str()),
+        (2, 1, "compartments share no item")
+    );
+    let e = error::<day3::Inputs>("aa\nbb\n");
+    assert_eq!(e.message, "elves must come in groups of three");
+    let e = error::<day3::Inputs>("aa\nbb\naa\ncc\ncc\ncc\n");
+    assert_eq!((e.line, e.message.as_str()), (1, "group has no badge"));
+
+    // Nothing needs deleting when there's already room.
+    let dir = day7::Dir::parse("$ cd /\n$ ls\n100 a\n").unwrap();
+    assert_eq!(dir.part2(), 0);
+    let dir = day7::Dir::parse("$ cd /\n$ ls\n50000000 a\n").unwrap();
+    assert_eq!(dir.part2(), 50_000_000);
+
+    let e = error::<day12::Grid>("SbE\n");
+    assert_eq!(
+        (e.line, e.column, e.message.as_str()),
+        (1, 1, "no path from S to E")
+    );
+
+    let e = error::<day15::Input>("");
+    assert_eq!(e.message, "no sensors found");
+    let sensor = |beacon_y| {
+        day15::Input::parse(&format!(
+            "Sensor at x=0, y=0: closest beacon is at x=0, y={}\n",
+            beacon_y
+        ))
+        .unwrap()
+    };
+    // The gap is at the edge of the search area, past the last range on its row.
+    assert_eq!(sensor(1).part2(1), Some(4_000_001));
+    assert_eq!(sensor(2).part2(1), None);
+}
@@ -0,0 +1,13 @@
+//! Mangled inputs give parse errors, never panics.
+
+use advent2022::fuzz::fuzz;
+use advent2022::DAYS;
+
+#[test]
+fn parsers_dont_panic() {
+    for day in DAYS {
+        if let Err(e) = fuzz(day, day.number as u64, 300) {
+            panic!("{}", e);
+        }
+    }
+}
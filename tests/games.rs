@@ -0,0 +1,221 @@
+use advent2022::days::day2::{self, Input, Move, Outcome, Response, Round, Rules, Shape};
+use advent2022::Solution;
+
+fn round(theirs: usize, ours: usize) -> Round {
+    Round { theirs, ours }
+}
+
+#[test]
+fn rock_paper_scissors() {
+    let (part1, part2) = (Rules::part1(), Rules::part2());
+    assert_eq!(part1.check(), Ok(()));
+    // Paper against rock, and aiming to draw against rock.
+    let a_y = part1.parse_round("A Y").unwrap();
+    assert_eq!(a_y, round(0, 1));
+    assert_eq!(part1.score(a_y), Some(8));
+    assert_eq!(part2.score(a_y), Some(4));
+    // Scissors against scissors, and aiming to win against scissors.
+    assert_eq!(part1.score(round(2, 2)), Some(6));
+    assert_eq!(part2.score(round(2, 2)), Some(7));
+    assert_eq!(part1.score(round(3, 2)), None);
+
+    let inputs = day2::Inputs::parse("A Y\nB X\nC Z\n").unwrap();
+    assert_eq!(part1.total(&inputs.rounds()), Some(inputs.part1()));
+    assert_eq!(inputs.part2(), 12);
+}
+
+#[test]
+fn lizard_spock() {
+    let rules = Rules::rpsls();
+    assert_eq!(rules.check(), Ok(()));
+    let names = ["rock", "paper", "scissors", "lizard", "spock"];
+    let beats = [
+        ("rock", "scissors"),
+        ("rock", "lizard"),
+        ("paper", "rock"),
+        ("paper", "spock"),
+        ("scissors", "paper"),
+        ("scissors", "lizard"),
+        ("lizard", "paper"),
+        ("lizard", "spock"),
+        ("spock", "rock"),
+        ("spock", "scissors"),
+    ];
+    for (i, &theirs) in names.iter().enumerate() {
+        for (j, &ours) in names.iter().enumerate() {
+            // Symbols `A` to `E` and `V` to `Z` are the shapes in the order of `names`.
+            let line = format!("{} {}", (b'A' + i as u8) as char, (b'V' + j as u8) as char);
+            let r = rules.parse_round(&line).unwrap();
+            let outcome = if beats.contains(&(ours, theirs)) {
+                6
+            } else if i == j {
+                3
+            } else {
+                0
+            };
+            assert_eq!(
+                rules.score(r),
+                Some(j as i64 + 1 + outcome),
+                "{} against {}",
+                ours,
+                theirs
+            );
+        }
+    }
+
+    // Aiming for an outcome works with any number of shapes.
+    for shapes in [3, 5, 7, 9] {
+        for theirs in 0..shapes {
+            for outcome in [Outcome::Lose, Outcome::Draw, Outcome::Win] {
+                let rules = Rules {
+                    shape_names: vec![""; shapes],
+                    shape_scores: vec![0; shapes],
+                    ..Rules::part1()
+                };
+                let ours = rules.play(theirs, Move::Outcome(outcome)).unwrap();
+                assert_eq!(Outcome::of(ours, theirs, shapes), outcome);
+            }
+        }
+    }
+}
+
+#[test]
+fn custom_rules() {
+    let rules = Rules {
+        shape_names: vec!["r", "p", "s"],
+        shape_scores: vec![10, 20, 30],
+        outcome_scores: [-1, 0, 1],
+        theirs: vec![('r', 0), ('p', 1), ('s', 2)],
+        ours: vec![('R', Move::Shape(0)), ('!', Move::Outcome(Outcome::Win))],
+    };
+    let rounds = rules.parse("s R\np !\n").unwrap();
+    assert_eq!(rounds, [round(2, 0), round(1, 1)]);
+    assert_eq!(rules.total(&rounds), Some(11 + 31));
+
+    let e = rules.parse("s R\np X\n").unwrap_err();
+    assert_eq!(
+        (e.line, e.column, e.message.as_str()),
+        (2, 3, "expected R or !")
+    );
+    let e = rules.parse("s R\nrp R\n").unwrap_err();
+    assert_eq!(
+        (e.line, e.column, e.message.as_str()),
+        (2, 1, "expected r, p or s")
+    );
+
+    let even = Rules {
+        shape_names: vec!["a", "b", "c", "d"],
+        shape_scores: vec![1, 2, 3, 4],
+        ..Rules::part1()
+    };
+    assert_eq!(
+        even.check(),
+        Err("need an odd number of shapes, not 4".into())
+    );
+    // An even cycle would favour some shapes, so it isn't scored at all.
+    assert_eq!(even.score(round(0, 0)), None);
+    assert_eq!(even.total(&[]), None);
+    assert_eq!(even.range(&[]), None);
+    let missing = Rules {
+        theirs: vec![('A', 3)],
+        ..Rules::part1()
+    };
+    assert_eq!(missing.check(), Err("A stands for shape 3 of 3".into()));
+}
+
+#[test]
+fn invalid_shapes() {
+    // Rules that fail `check` score nothing rather than panic.
+    let beyond = Rules {
+        ours: vec![('X', Move::Shape(5))],
+        ..Rules::part1()
+    };
+    assert_eq!(beyond.play(0, Move::Shape(5)), None);
+    assert_eq!(beyond.score(round(0, 0)), None);
+    assert_eq!(beyond.total(&[round(0, 0)]), None);
+    assert_eq!(beyond.describe(Move::Shape(5)), "shape 5");
+
+    let theirs = Rules {
+        theirs: vec![('A', 3)],
+        ..Rules::part1()
+    };
+    assert_eq!(theirs.score(round(0, 0)), None);
+    assert_eq!(theirs.range(&[round(0, 0)]), None);
+
+    let empty = Rules {
+        shape_names: vec![],
+        shape_scores: vec![],
+        ..Rules::part2()
+    };
+    assert_eq!(empty.play(0, Move::Outcome(Outcome::Win)), None);
+    assert_eq!(empty.score(round(0, 1)), None);
+    assert_eq!(empty.range(&[round(0, 1)]), None);
+    assert_eq!(empty.range(&[]), None);
+}
+
+#[test]
+fn analysis() {
+    let inputs = day2::Inputs::parse("A Y\nB X\nC Z\n").unwrap();
+    let analysis = inputs.analyze();
+    // Paper, scissors and rock would win every round; rock, paper and scissors lose them all.
+    assert_eq!((analysis.best, analysis.worst), (24, 6));
+    assert_eq!(analysis.readings.len(), 12);
+
+    let totals: Vec<_> = analysis.readings.iter().map(|r| (r.0, r.2)).collect();
+    assert_eq!(totals[0], ("part1", inputs.part1()));
+    assert_eq!(totals[6], ("part2", inputs.part2()));
+    for (name, rules, total) in &analysis.readings {
+        assert!(
+            (analysis.worst..=analysis.best).contains(total),
+            "{} {:?}",
+            name,
+            rules
+        );
+    }
+
+    let (name, rules, total) = analysis.likely();
+    assert_eq!((*name, *total), ("part1", 24));
+    let meant: Vec<_> = rules
+        .ours
+        .iter()
+        .map(|&(c, m)| (c, rules.describe(m)))
+        .collect();
+    assert_eq!(
+        meant,
+        [
+            ('X', "scissors".into()),
+            ('Y', "paper".into()),
+            ('Z', "rock".into())
+        ]
+    );
+}
+
+#[test]
+fn typed_scores() {
+    let input: Input = "A Y".parse().unwrap();
+    assert_eq!(
+        input,
+        Input {
+            other: Shape::Rock,
+            me: Response::Y
+        }
+    );
+    assert_eq!((input.score1(), input.score2()), (8, 4));
+    assert_eq!(input.round(), round(0, 1));
+    // A line reads the same way, with the same errors, however it is parsed.
+    let e = "A Q".parse::<Input>().unwrap_err();
+    assert_eq!((e.column, e.message.as_str()), (3, "expected X, Y or Z"));
+
+    assert_eq!(Shape::Paper.against(Shape::Rock), Outcome::Win);
+    assert_eq!(Shape::Rock.for_outcome(Outcome::Lose), Shape::Scissors);
+    // Every line scores the same typed as it does through the engine.
+    for other in "ABC".chars() {
+        for me in "XYZ".chars() {
+            let line = format!("{} {}", other, me);
+            let input: Input = line.parse().unwrap();
+            let r = Rules::part1().parse_round(&line).unwrap();
+            assert_eq!(Some(input.score1()), Rules::part1().score(r));
+            assert_eq!(Some(input.score2()), Rules::part2().score(r));
+        }
+    }
+}
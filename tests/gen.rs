@@ -0,0 +1,52 @@
+//! Generated inputs must parse and be solvable.
+
+use advent2022::gen::generate;
+use advent2022::DAYS;
+
+#[test]
+fn every_day_solves() {
+    for day in DAYS {
+        for seed in 0..5 {
+            let input = generate(day.number, seed, 20).unwrap();
+            let parsed = day
+                .parse(&input)
+                .unwrap_or_else(|e| panic!("day {} seed {}: {}\n{}", day.number, seed, e, input));
+            parsed.part1();
+            // Day 15's part 2 scans millions of rows; its gap is checked below instead.
+            if day.number != 15 {
+                parsed.part2();
+            }
+        }
+    }
+}
+
+#[test]
+fn reproducible() {
+    for day in DAYS {
+        assert_eq!(
+            generate(day.number, 7, 30),
+            generate(day.number, 7, 30),
+            "day {}",
+            day.number
+        );
+    }
+    assert_ne!(generate(8, 1, 30), generate(8, 2, 30));
+    assert_eq!(generate(17, 1, 30), None);
+}
+
+#[test]
+fn day15_has_one_gap() {
+    let input = generate(15, 3, 10).unwrap();
+    let day = advent2022::day(15).unwrap();
+    let parsed = day.parse(&input).unwrap();
+    let answer: i64 = parsed.part2().parse().unwrap();
+    let (x, y) = (answer / 4_000_000, answer % 4_000_000);
+    assert!((0..=4_000_000).contains(&x) && (0..=4_000_000).contains(&y));
+}
+
+#[test]
+fn day16_stays_searchable() {
+    let input = generate(16, 1, 600).unwrap();
+    let worth = input.lines().filter(|l| !l.contains("rate=0;")).count();
+    assert_eq!((input.lines().count(), worth), (600, 8));
+}
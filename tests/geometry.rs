@@ -0,0 +1,55 @@
+use advent2022::geometry::{Bounds, Dir, Point, Vec2};
+
+#[test]
+fn arithmetic() {
+    let (a, b) = (Point::new(1, 2), Vec2::new(3, -4));
+    assert_eq!(a + b, Point::new(4, -2));
+    assert_eq!(a - b, Point::new(-2, 6));
+    assert_eq!(b * 2, Vec2::new(6, -8));
+    assert_eq!(-b, Vec2::new(-3, 4));
+    assert_eq!(b.signum(), Vec2::new(1, -1));
+
+    let mut c = a;
+    c += b;
+    c -= b;
+    assert_eq!(c, a);
+}
+
+#[test]
+fn distances() {
+    let (a, b) = (Point::new(0, 0), Point::new(3, -4));
+    assert_eq!(a.manhattan_to(b), 7);
+    assert_eq!(a.chebyshev_to(b), 4);
+    assert_eq!(a.step_towards(b), Point::new(1, -1));
+    assert_eq!(b.step_towards(b), b);
+}
+
+#[test]
+fn parse() {
+    assert_eq!("498,4".parse(), Ok(Point::new(498, 4)));
+    assert_eq!("-1, 2".parse(), Ok(Point::new(-1, 2)));
+    assert_eq!(Point::new(-1, 2).to_string(), "-1,2");
+    assert!("498".parse::<Point>().is_err());
+    assert!("498,x".parse::<Point>().is_err());
+}
+
+#[test]
+fn directions() {
+    for d in Dir::ALL {
+        assert_eq!(d.opposite().offset(), -d.offset());
+        assert_eq!(d.turn_left().turn_right(), d);
+    }
+    assert_eq!(Dir::Up.turn_right(), Dir::Right);
+    assert_eq!("L".parse(), Ok(Dir::Left));
+    assert!("X".parse::<Dir>().is_err());
+}
+
+#[test]
+fn bounds() {
+    let b = Bounds::of([Point::new(2, 5), Point::new(-1, 3), Point::new(0, 7)]).unwrap();
+    assert_eq!((b.min, b.max), (Point::new(-1, 3), Point::new(2, 7)));
+    assert_eq!((b.width(), b.height()), (4, 5));
+    assert!(b.contains(Point::new(2, 3)));
+    assert!(!b.contains(Point::new(3, 3)));
+    assert_eq!(Bounds::of([]), None);
+}
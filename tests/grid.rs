@@ -0,0 +1,56 @@
+use advent2022::geometry::{Point, Vec2};
+use advent2022::grid::Grid;
+
+fn digits(input: &str) -> Grid<u32> {
+    Grid::parse(input, |_, c| c.to_digit(10).ok_or("expected a digit")).unwrap()
+}
+
+#[test]
+fn parse_and_render() {
+    let grid = digits("123\n456\n");
+    assert_eq!((grid.width(), grid.height()), (3, 2));
+    assert_eq!(grid[Point::new(2, 1)], 6);
+    assert_eq!(grid.get(Point::new(3, 0)), None);
+    assert_eq!(grid.get(Point::new(0, -1)), None);
+    assert_eq!(
+        grid.render(|&d| char::from_digit(d, 10).unwrap()),
+        "123\n456\n"
+    );
+}
+
+#[test]
+fn parse_errors() {
+    let e = Grid::parse("12\n3x\n", |_, c| c.to_digit(10).ok_or("expected a digit")).unwrap_err();
+    assert_eq!((e.line, e.column), (2, 2));
+    assert_eq!(e.message, "expected a digit");
+
+    let e = Grid::parse("12\n345\n", |_, c| Ok::<_, String>(c)).unwrap_err();
+    assert_eq!((e.line, e.column), (2, 1));
+
+    assert!(Grid::parse("", |_, c| Ok::<_, String>(c)).is_err());
+}
+
+#[test]
+fn neighbours() {
+    let grid = digits("123\n456\n789\n");
+    let mut n4: Vec<Point> = grid.neighbours4(Point::new(0, 0)).collect();
+    n4.sort();
+    assert_eq!(n4, [Point::new(0, 1), Point::new(1, 0)]);
+    assert_eq!(grid.neighbours4(Point::new(1, 1)).count(), 4);
+    assert_eq!(grid.neighbours8(Point::new(1, 1)).count(), 8);
+    assert_eq!(grid.neighbours8(Point::new(2, 2)).count(), 3);
+}
+
+#[test]
+fn walkers() {
+    let grid = digits("123\n456\n789\n");
+    let values = |ps: Vec<Point>| ps.into_iter().map(|p| grid[p]).collect::<Vec<_>>();
+    assert_eq!(values(grid.row(1).collect()), [4, 5, 6]);
+    assert_eq!(values(grid.column(2).rev().collect()), [9, 6, 3]);
+    assert_eq!(
+        values(grid.ray(Point::new(0, 0), Vec2::new(1, 1)).collect()),
+        [5, 9]
+    );
+    assert_eq!(grid.ray(Point::new(1, 1), Vec2::new(0, -1)).count(), 1);
+    assert_eq!(grid.find(|&d| d == 8), Some(Point::new(1, 2)));
+}
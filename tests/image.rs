@@ -0,0 +1,112 @@
+//! The image encoders write files other tools can read, and days draw their state.
+
+use advent2022::image::{adler32, crc32, Rgb};
+use advent2022::Image;
+
+/// The chunks of a PNG file, checking each one's CRC.
+fn chunks(png: &[u8]) -> Vec<(String, Vec<u8>)> {
+    assert_eq!(&png[..8], b"\x89PNG\r\n\x1a\n");
+    let mut chunks = Vec::new();
+    let mut rest = &png[8..];
+    while !rest.is_empty() {
+        let len = u32::from_be_bytes(rest[..4].try_into().unwrap()) as usize;
+        let (kind, data) = (&rest[4..8], &rest[8..8 + len]);
+        let crc = u32::from_be_bytes(rest[8 + len..12 + len].try_into().unwrap());
+        assert_eq!(crc, crc32(&rest[4..8 + len]));
+        chunks.push((String::from_utf8(kind.to_vec()).unwrap(), data.to_vec()));
+        rest = &rest[12 + len..];
+    }
+    chunks
+}
+
+/// Undo the encoder's zlib stream, which only ever has stored blocks.
+fn unstore(zlib: &[u8]) -> Vec<u8> {
+    assert_eq!(&zlib[..2], [0x78, 0x01]);
+    let mut out = Vec::new();
+    let mut at = 2;
+    loop {
+        let last = zlib[at] & 1 == 1;
+        assert_eq!(zlib[at] >> 1, 0, "only stored blocks");
+        let len = u16::from_le_bytes([zlib[at + 1], zlib[at + 2]]);
+        let nlen = u16::from_le_bytes([zlib[at + 3], zlib[at + 4]]);
+        assert_eq!(len, !nlen);
+        out.extend(&zlib[at + 5..at + 5 + len as usize]);
+        at += 5 + len as usize;
+        if last {
+            break;
+        }
+    }
+    assert_eq!(zlib[at..], adler32(&out).to_be_bytes());
+    out
+}
+
+#[test]
+fn checksums() {
+    assert_eq!(crc32(b"IEND"), 0xae42_6082);
+    assert_eq!(crc32(b"123456789"), 0xcbf4_3926);
+    assert_eq!(adler32(b"Wikipedia"), 0x11e6_0398);
+}
+
+#[test]
+fn png() {
+    let mut image = Image::new(2, 2, Rgb::BLACK);
+    image.set(1, 0, Rgb(1, 2, 3));
+    let parts = chunks(&image.to_png());
+    let kinds = parts.iter().map(|(k, _)| k.as_str()).collect::<Vec<_>>();
+    assert_eq!(kinds, ["IHDR", "IDAT", "IEND"]);
+    assert_eq!(parts[0].1, [0, 0, 0, 2, 0, 0, 0, 2, 8, 2, 0, 0, 0]);
+    assert_eq!(
+        unstore(&parts[1].1),
+        [0, 0, 0, 0, 1, 2, 3, 0, 0, 0, 0, 0, 0, 0]
+    );
+
+    // Big enough to need several stored blocks.
+    let image = Image::new(300, 100, Rgb(9, 8, 7));
+    let raw = unstore(&chunks(&image.to_png())[1].1);
+    assert_eq!(raw.len(), 100 * (300 * 3 + 1));
+    assert_eq!(raw[..4], [0, 9, 8, 7]);
+}
+
+#[test]
+fn ppm() {
+    let image = Image::from_text("#.\n#", |c| if c == '#' { Rgb::WHITE } else { Rgb::BLACK });
+    assert_eq!((image.width(), image.height()), (2, 2));
+    let mut expected = b"P6\n2 2\n255\n".to_vec();
+    expected.extend([255, 255, 255, 0, 0, 0, 255, 255, 255, 0, 0, 0]);
+    assert_eq!(image.to_ppm(), expected);
+
+    let big = image.scaled(3);
+    assert_eq!((big.width(), big.height()), (6, 6));
+    assert_eq!(big.get(2, 5), Rgb::WHITE);
+    assert_eq!(big.get(3, 0), Rgb::BLACK);
+}
+
+fn pictures(day: u32) -> Vec<(&'static str, Image)> {
+    let day = advent2022::day(day).unwrap();
+    day.parse_file(&day.input_path(true)).unwrap().pictures()
+}
+
+#[test]
+fn day_pictures() {
+    let crt = &pictures(10)[0].1;
+    assert_eq!((crt.width(), crt.height()), (40, 6));
+    let lit = (0..4)
+        .map(|x| crt.get(x, 0) == crt.get(0, 0))
+        .collect::<Vec<_>>();
+    assert_eq!(lit, [true, true, false, false]);
+
+    let heights = &pictures(8)[0];
+    assert_eq!(heights.0, "heights");
+    assert_eq!((heights.1.width(), heights.1.height()), (5, 5));
+
+    // The path up the hill is drawn over the start and the end.
+    let hill = pictures(12);
+    assert_eq!(hill[0].1.get(0, 0), Rgb::RED);
+    assert_eq!(hill[0].1.get(5, 2), Rgb::RED);
+
+    let names = pictures(14).into_iter().map(|(n, _)| n).collect::<Vec<_>>();
+    assert_eq!(names, ["part1", "part2"]);
+    let rope = &pictures(9)[0].1;
+    assert_eq!((rope.width(), rope.height()), (5, 5));
+    assert_eq!(rope.get(0, 4), Rgb::RED);
+}
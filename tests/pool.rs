@@ -0,0 +1,84 @@
+use std::panic;
+use std::time::Duration;
+
+use advent2022::pool;
+use advent2022::report::{summary, Outcome, Phase, Run};
+
+#[test]
+fn results_in_order() {
+    let jobs: Vec<u64> = (0..50).collect();
+    let results = pool::map(jobs, 4, |n| {
+        // Make later jobs finish first.
+        std::thread::sleep(Duration::from_micros(50 - n));
+        n * n
+    });
+    let squares: Vec<u64> = results.into_iter().map(Result::unwrap).collect();
+    assert_eq!(squares, (0..50).map(|n| n * n).collect::<Vec<_>>());
+}
+
+#[test]
+fn panics_are_isolated() {
+    let hook = panic::take_hook();
+    panic::set_hook(Box::new(|_| {}));
+    let results = pool::map(vec![1, 2, 3, 4], 2, |n| {
+        if n == 2 {
+            panic!("unreachable");
+        }
+        if n == 3 {
+            panic!("bad job {}", n);
+        }
+        n
+    });
+    panic::set_hook(hook);
+    assert_eq!(
+        results,
+        vec![
+            Ok(1),
+            Err("unreachable".to_owned()),
+            Err("bad job 3".to_owned()),
+            Ok(4)
+        ]
+    );
+    assert!(pool::map(Vec::<u32>::new(), 4, |n| n).is_empty());
+}
+
+#[test]
+fn summary_table() {
+    let run = Run {
+        answers: vec![(1, "24000".to_owned()), (2, "##\n.#\n".to_owned())],
+        times: vec![
+            (Phase::Parse, Duration::from_micros(2)),
+            (Phase::Part1, Duration::from_micros(3)),
+            (Phase::Part2, Duration::from_micros(5)),
+        ],
+    };
+    let outcomes = [
+        Outcome {
+            day: 1,
+            title: "Calorie Counting",
+            result: Ok(run),
+        },
+        Outcome {
+            day: 15,
+            title: "Beacon Exclusion Zone",
+            result: Err("panicked: unreachable".to_owned()),
+        },
+    ];
+    let lines = summary(&outcomes, Duration::from_millis(1));
+    assert_eq!(
+        lines,
+        [
+            "day  title                  part 1  part 2     parse  solve 1  solve 2   total",
+            "---  ---------------------  ------  ---------  -----  -------  -------  ------",
+            "  1  Calorie Counting       24000   (2 lines)  2.0µs    3.0µs    5.0µs  10.0µs",
+            " 15  Beacon Exclusion Zone  error   -              -        -        -       -",
+            "",
+            "1 of 2 days solved in 1.0ms",
+            "",
+            "Day 1 part 2:",
+            "##",
+            ".#",
+            "Day 15 failed: panicked: unreachable",
+        ]
+    );
+}
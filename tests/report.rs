@@ -0,0 +1,44 @@
+use std::time::Duration;
+
+use advent2022::report::{Phase, Run};
+
+fn run(answers: &[(u32, &str)], times: &[u64]) -> Run {
+    let phases = [Phase::Parse, Phase::Part1, Phase::Part2];
+    Run {
+        answers: answers.iter().map(|&(p, a)| (p, a.to_owned())).collect(),
+        times: phases
+            .into_iter()
+            .zip(times.iter().map(|&ms| Duration::from_millis(ms)))
+            .collect(),
+    }
+}
+
+#[test]
+fn diff() {
+    let first = run(&[(1, "24"), (2, "93")], &[1, 2, 4]);
+    assert_eq!(
+        first.diff(None),
+        [
+            "Part 1: 24",
+            "Part 2: 93",
+            "Time: parse 1.0ms, part1 2.0ms, part2 4.0ms"
+        ]
+    );
+
+    let second = run(&[(1, "25"), (2, "93")], &[1, 3, 2]);
+    assert_eq!(
+        second.diff(Some(&first)),
+        [
+            "Part 1: 25 (was 24)",
+            "Part 2: 93 (same)",
+            "Time: parse 1.0ms (+0%), part1 3.0ms (+50%), part2 2.0ms (-50%)"
+        ]
+    );
+
+    let crt = run(&[(2, "#.\n.#\n")], &[1]);
+    let changed = run(&[(2, "##\n.#\n")], &[1]);
+    assert_eq!(
+        changed.diff(Some(&crt)),
+        ["Part 2 (changed):", "##", ".#", "Time: parse 1.0ms (+0%)"]
+    );
+}
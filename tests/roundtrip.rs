@@ -0,0 +1,57 @@
+//! Printing a parsed input and parsing it again gives back the same thing.
+
+use std::fmt::{Debug, Display};
+use std::fs;
+
+use advent2022::days::*;
+use advent2022::gen::generate;
+use advent2022::Solution;
+
+fn roundtrip<S: Solution + Display + PartialEq + Debug>(input: &str) -> String {
+    let parsed = S::parse(input).unwrap();
+    let printed = parsed.to_string();
+    let reparsed = S::parse(&printed).unwrap_or_else(|e| panic!("{}\n{}", e, printed));
+    assert_eq!(reparsed, parsed);
+    assert_eq!(reparsed.to_string(), printed);
+    printed
+}
+
+/// Round-trip generated inputs, which are already in the printed form, and the bundled ones.
+fn check<S: Solution + Display + PartialEq + Debug>(day: u32) {
+    for seed in 0..50 {
+        let input = generate(day, seed, (seed as usize % 10) * 5).unwrap();
+        assert_eq!(roundtrip::<S>(&input), input, "day {} seed {}", day, seed);
+    }
+    for file in ["", "-sample"] {
+        let input = fs::read_to_string(format!("data/day{}{}.txt", day, file)).unwrap();
+        roundtrip::<S>(&input);
+    }
+}
+
+/// Every prefix of a valid input either parses or gives an error, without panicking.
+fn truncations<S: Solution>(day: u32) {
+    for seed in 0..5 {
+        let input = generate(day, seed, 5).unwrap();
+        for end in (0..input.len()).filter(|&i| input.is_char_boundary(i)) {
+            let _ = S::parse(&input[..end]);
+        }
+    }
+}
+
+#[test]
+fn day5_stacks() {
+    check::<day5::Input>(5);
+    truncations::<day5::Input>(5);
+}
+
+#[test]
+fn day13_packets() {
+    check::<day13::Input>(13);
+    truncations::<day13::Input>(13);
+}
+
+#[test]
+fn day14_paths() {
+    check::<day14::Cave>(14);
+    truncations::<day14::Cave>(14);
+}
@@ -0,0 +1,235 @@
+//! Known answers for the sample inputs given in each day's puzzle text.
+
+use std::fs;
+use std::path::Path;
+
+use advent2022::days::*;
+use advent2022::{Params, Solution, DAYS};
+
+fn sample<S: Solution>(file: &str) -> S {
+    let path = format!("data/{}.txt", file);
+    let contents = fs::read_to_string(&path).unwrap();
+    let mut parsed = S::parse(&contents).unwrap();
+    let params = Params::for_input(Path::new(&path)).unwrap();
+    parsed.configure(&params).unwrap();
+    parsed
+}
+
+#[test]
+fn every_day_has_a_sample() {
+    for day in DAYS {
+        day.parse_file(&day.input_path(true)).unwrap();
+    }
+}
+
+#[test]
+fn day1() {
+    let elves: day1::Elves = sample("day1-sample");
+    assert_eq!(elves.part1(), 24000);
+    assert_eq!(elves.part2(), 45000);
+}
+
+#[test]
+fn day2() {
+    let inputs: day2::Inputs = sample("day2-sample");
+    assert_eq!(inputs.part1(), 15);
+    assert_eq!(inputs.part2(), 12);
+}
+
+#[test]
+fn day3() {
+    let inputs: day3::Inputs = sample("day3-sample");
+    assert_eq!(inputs.part1(), 157);
+    assert_eq!(inputs.part2(), 70);
+}
+
+#[test]
+fn day4() {
+    let inputs: day4::Inputs = sample("day4-sample");
+    assert_eq!(inputs.part1(), 2);
+    assert_eq!(inputs.part2(), 4);
+}
+
+#[test]
+fn day5() {
+    let input: day5::Input = sample("day5-sample");
+    assert_eq!(input.part1(), "CMZ");
+    assert_eq!(input.part2(), "MCD");
+}
+
+#[test]
+fn day6() {
+    let signal: day6::Signal = sample("day6-sample");
+    assert_eq!(signal.part1(), 7);
+    assert_eq!(signal.part2(), 19);
+
+    let others = [
+        ("bvwbjplbgvbhsrlpgdmjqwftvncz", 5, 23),
+        ("nppdvjthqldpwncqszvftbrmjlhg", 6, 23),
+        ("nznrnfrfntjfmvfwmzdfjlvtqnbhcprsg", 10, 29),
+        ("zcfzfwzzqfrljwzlrfnpqdbhtmscgvjw", 11, 26),
+    ];
+    for (s, p1, p2) in others {
+        let signal = day6::Signal::parse(s).unwrap();
+        assert_eq!(signal.part1(), p1, "{}", s);
+        assert_eq!(signal.part2(), p2, "{}", s);
+    }
+}
+
+#[test]
+fn day7() {
+    let dir: day7::Dir = sample("day7-sample");
+    assert_eq!(dir.part1(), 95437);
+    assert_eq!(dir.part2(), 24933642);
+}
+
+#[test]
+fn day8() {
+    let grid: day8::Grid = sample("day8-sample");
+    assert_eq!(grid.part1(), 21);
+    assert_eq!(grid.part2(), 8);
+}
+
+#[test]
+fn day9() {
+    let motions: day9::Motions = sample("day9-sample");
+    assert_eq!(motions.part1(), 13);
+    assert_eq!(motions.part2(), 1);
+
+    let motions: day9::Motions = sample("day9-sample2");
+    assert_eq!(motions.part1(), 88);
+    assert_eq!(motions.part2(), 36);
+}
+
+#[test]
+fn day10() {
+    let program: day10::Program = sample("day10-sample");
+    assert_eq!(program.part1(), 13140);
+    assert_eq!(
+        program.part2(),
+        "\
+##..##..##..##..##..##..##..##..##..##..
+###...###...###...###...###...###...###.
+####....####....####....####....####....
+#####.....#####.....#####.....#####.....
+######......######......######......####
+#######.......#######.......#######.....
+"
+    );
+}
+
+#[test]
+fn day11() {
+    let notes: day11::Notes = sample("day11-sample");
+    assert_eq!(notes.part1(), 10605);
+    assert_eq!(notes.part2(), 2713310158);
+}
+
+#[test]
+fn day12() {
+    let grid: day12::Grid = sample("day12-sample");
+    assert_eq!(grid.part1(), 31);
+    assert_eq!(grid.part2(), 29);
+}
+
+#[test]
+fn day13() {
+    let input: day13::Input = sample("day13-sample");
+    assert_eq!(input.part1(), 13);
+    assert_eq!(input.part2(), 140);
+}
+
+#[test]
+fn day14() {
+    let cave: day14::Cave = sample("day14-sample");
+    assert_eq!(cave.part1(), 24);
+    assert_eq!(cave.part2(), 93);
+}
+
+#[test]
+fn day15() {
+    let input: day15::Input = sample("day15-sample");
+    assert_eq!(Solution::part1(&input), 26);
+    assert_eq!(Solution::part2(&input), "56000011");
+    assert_eq!(input.part1(10), 26);
+    assert_eq!(input.part2(20), Some(56000011));
+}
+
+#[test]
+fn day16() {
+    let input: day16::Input = sample("day16-sample");
+    assert_eq!(input.part1(), 1651);
+    assert_eq!(input.part2(), 1707);
+}
+
+#[test]
+fn parse_reader() {
+    let input = "2-4,6-8\n2-3,4-5\n".as_bytes();
+    let inputs = day4::Inputs::parse_reader(input).unwrap();
+    assert_eq!(inputs.part1(), 0);
+
+    let day = advent2022::day(4).unwrap();
+    let parsed = day.parse_reader("2-8,3-7\n".as_bytes()).unwrap();
+    assert_eq!(parsed.part1(), "1");
+}
+
+#[test]
+fn params() {
+    fn with<S: Solution>(file: &str, params: &[&str]) -> S {
+        let mut parsed: S = sample(file);
+        let mut p = Params::new();
+        for flag in params {
+            p.set_flag(flag).unwrap();
+        }
+        parsed.configure(&p).unwrap();
+        parsed
+    }
+
+    let signal: day6::Signal = with("day6-sample", &["part1_window=14"]);
+    assert_eq!(signal.part1(), 19);
+
+    let motions: day9::Motions = with("day9-sample2", &["part1_len=10"]);
+    assert_eq!(motions.part1(), 36);
+
+    // With no relief, the puzzle text gives the counts after one round as 2, 4, 3 and 6.
+    let notes: day11::Notes = with("day11-sample", &["part1_rounds=1", "relief=1"]);
+    assert_eq!(notes.part1(), 24);
+    // Part 1's 20 rounds used to overflow without relief; they match part 2's first 20.
+    let notes: day11::Notes = with("day11-sample", &["relief=1"]);
+    assert_eq!(notes.part1(), 103 * 99);
+
+    let input: day15::Input = with("day15-sample", &["row=9"]);
+    assert_eq!(Solution::part1(&input), 25);
+
+    let input: day16::Input = with("day16-sample", &["part1_minutes=10", "start=DD"]);
+    assert_eq!(input.part1(), 300);
+
+    let mut signal: day6::Signal = sample("day6-sample");
+    let mut p = Params::new();
+    p.set("part1_window", "27");
+    let no_marker = "no marker of 27 different characters found".to_owned();
+    assert_eq!(signal.configure(&p), Err(no_marker));
+    p.set("part1_window", "0");
+    assert!(signal.configure(&p).is_err());
+
+    let mut input: day16::Input = sample("day16-sample");
+    let mut p = Params::new();
+    p.set("start", "ZZ");
+    assert_eq!(input.configure(&p), Err("no valve ZZ".to_owned()));
+
+    // Inputs without the usual start are fine, as long as they say where to start instead.
+    let renamed = fs::read_to_string("data/day16-sample.txt")
+        .unwrap()
+        .replace("AA", "ZZ");
+    // Unconfigured, there is nowhere to start, so nothing is released.
+    let parsed = advent2022::day(16).unwrap().parse(&renamed).unwrap();
+    assert_eq!((parsed.part(1), parsed.part(2)), ("0".into(), "0".into()));
+    assert_eq!(parsed.variant("bitmask", 2), Some("0".into()));
+    let mut input = day16::Input::parse(&renamed).unwrap();
+    assert_eq!(
+        input.configure(&Params::new()),
+        Err("no starting valve AA".to_owned())
+    );
+    input.configure(&p).unwrap();
+    assert_eq!(input.part1(), 1651);
+}
@@ -0,0 +1,72 @@
+//! Every solver variant agrees with its day's main solver.
+
+use advent2022::gen::generate;
+use advent2022::{Answers, Day, DAYS};
+
+fn check(day: &Day, name: &str, parsed: &dyn Answers, parts: &[u32]) {
+    for variant in day.variants() {
+        for &part in parts {
+            if let Some(answer) = parsed.variant(variant, part) {
+                assert_eq!(
+                    answer,
+                    parsed.part(part),
+                    "day {} {} part {} {}",
+                    day.number,
+                    name,
+                    part,
+                    variant
+                );
+            }
+        }
+    }
+}
+
+#[test]
+fn variants_agree() {
+    for day in DAYS.iter().filter(|d| !d.variants().is_empty()) {
+        let sample = day.input_path(true);
+        let parsed = day.parse_file(&sample).unwrap();
+        check(day, &sample.display().to_string(), parsed.as_ref(), &[1, 2]);
+
+        // Generated day 15 inputs span millions of cells, which is too slow for a debug build
+        // to scan. `aoc crosscheck 15 --gen 10` checks them in release.
+        if day.number == 15 {
+            continue;
+        }
+        for seed in 0..10 {
+            let input = generate(day.number, seed, 10).unwrap();
+            let parsed = day.parse(&input).unwrap();
+            check(day, &format!("seed {}", seed), parsed.as_ref(), &[1, 2]);
+        }
+    }
+}
+
+#[test]
+fn listed() {
+    assert_eq!(advent2022::day(16).unwrap().variants(), ["bitmask"]);
+    assert_eq!(advent2022::day(2).unwrap().variants(), ["typed"]);
+    assert!(advent2022::day(1).unwrap().variants().is_empty());
+}
+
+#[test]
+fn bitmask_skips_big_inputs() {
+    // A line of 70 valves, all worth opening, which is far too many sets of them to tabulate.
+    let names: Vec<String> = (0..70)
+        .map(|i| format!("{}{}", (b'A' + i / 26) as char, (b'A' + i % 26) as char))
+        .collect();
+    let mut input = String::new();
+    for (i, name) in names.iter().enumerate() {
+        let next = &names[(i + 1) % names.len()];
+        input += &format!(
+            "Valve {} has flow rate=1; tunnel leads to valve {}\n",
+            name, next
+        );
+    }
+    let day = advent2022::day(16).unwrap();
+    let parsed = day.parse(&input).unwrap();
+    assert!(!parsed.handles("bitmask"));
+    assert_eq!(parsed.variant("bitmask", 1), None);
+
+    let sample = day.parse_file(&day.input_path(true)).unwrap();
+    assert!(sample.handles("bitmask"));
+}
@@ -0,0 +1,103 @@
+//! Simulations show each step to a viewer, ending on their answer.
+
+use std::fs;
+
+use advent2022::vis::{Frame, Hidden, Player, Recorder};
+use advent2022::Viewer;
+
+fn record(day: u32, part: u32, every: usize) -> (Recorder, String) {
+    let day = advent2022::day(day).unwrap();
+    let parsed = day.parse_file(&day.input_path(true)).unwrap();
+    let mut recorder = Recorder::new(every);
+    let answer = parsed.visualize(part, &mut recorder).unwrap();
+    assert_eq!(answer, parsed.part(part));
+    (recorder, answer)
+}
+
+#[test]
+fn sand() {
+    let (rec, answer) = record(14, 1, 1);
+    assert_eq!((rec.frames.len(), answer.as_str()), (24, "24"));
+    let last = rec.frames.last().unwrap();
+    assert_eq!(last.caption, "grain 24 rests at 495,8");
+    let picture = "\
+......+...
+..........
+......o...
+.....ooo..
+....#ooo##
+...o#ooo#.
+..###ooo#.
+....oooo#.
+.o.ooooo#.
+#########.
+..........";
+    assert_eq!(last.rows.join("\n"), picture);
+}
+
+#[test]
+fn crates() {
+    let (rec, _) = record(5, 2, 1);
+    let captions = rec.frames.iter().map(|f| f.caption.as_str());
+    assert_eq!(
+        captions.collect::<Vec<_>>(),
+        [
+            "move 1 from 2 to 1",
+            "move 3 from 1 to 3",
+            "move 2 from 2 to 1",
+            "move 1 from 1 to 2"
+        ]
+    );
+    assert_eq!(rec.frames[3].rows.last().unwrap(), " 1   2   3");
+}
+
+#[test]
+fn rope_and_crt() {
+    let (rec, _) = record(9, 1, 1);
+    assert_eq!(rec.frames.len(), 24);
+    assert_eq!(rec.frames[0].rows, ["1H"]);
+    assert_eq!(rec.frames[1].rows, ["s1H"]);
+
+    // Every 40th cycle, so one frame per finished row of the screen, plus the first.
+    let (rec, answer) = record(10, 2, 40);
+    assert_eq!(rec.frames.len(), 6);
+    assert!(rec.frames[0].rows[0].starts_with("sprite ###....."));
+    let screen = &rec.frames[5].rows[1..6];
+    assert_eq!(
+        screen.join("\n"),
+        answer.lines().take(5).collect::<Vec<_>>().join("\n")
+    );
+}
+
+#[test]
+fn frames() {
+    let frame = Frame::new("hello").text("ab\ncd");
+    assert_eq!(frame.to_string(), "hello\nab\ncd\n");
+
+    let mut hidden = Hidden;
+    hidden.show(&mut || panic!("hidden frames aren't drawn"));
+
+    let mut out = Vec::new();
+    let mut player = Player::new(&mut out, advent2022::vis::Mode::Play, 1000.0, 2);
+    for i in 0..3 {
+        player.show(&mut || Frame::new(format!("frame {}", i)));
+    }
+    let out = String::from_utf8(out).unwrap();
+    assert_eq!(
+        out,
+        "\x1b[2J\x1b[Hframe 0\nstep 1\n\x1b[2J\x1b[Hframe 2\nstep 3\n"
+    );
+}
+
+#[test]
+fn bundled_inputs() {
+    // The real inputs run to the end with a viewer attached too.
+    for day in [5, 9, 10] {
+        let d = advent2022::day(day).unwrap();
+        let input = fs::read_to_string(d.input_path(false)).unwrap();
+        let parsed = d.parse(&input).unwrap();
+        let mut rec = Recorder::new(1000);
+        assert_eq!(parsed.visualize(2, &mut rec), Some(parsed.part(2)));
+        assert!(!rec.frames.is_empty());
+    }
+}